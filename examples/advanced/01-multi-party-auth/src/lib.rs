@@ -1,7 +1,7 @@
 #![no_std]
 
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short, Address, Bytes, Env, Symbol, Vec,
+    contract, contractimpl, contracttype, symbol_short, Address, Bytes, Env, String, Symbol, Vec,
 };
 
 #[contract]
@@ -31,6 +31,47 @@ pub struct AuditTrailEventData {
     pub timestamp: u64,
 }
 
+/// Human-readable context for a proposal, fixed when it is created.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Proposal {
+    /// Short title shown to voters.
+    pub title: String,
+    /// Link to the full description (forum post, IPFS document, ...).
+    pub uri: String,
+    /// Ledger timestamp at creation.
+    pub created_at: u64,
+    /// Approval is refused at or after this ledger timestamp.
+    pub expires_at: u64,
+    /// Set once `proposal_approval` has met the threshold.
+    pub approved: bool,
+    /// Set once `execute` has applied the proposal's action.
+    pub executed: bool,
+    /// Set by `expire_proposal`; an expired proposal is terminal.
+    pub expired: bool,
+}
+
+/// Payload of the `threshold_changed` event.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ThresholdChangedEventData {
+    /// Signer group whose threshold changed.
+    pub proposal_id: Symbol,
+    /// The new threshold.
+    pub threshold: u32,
+}
+
+/// A typed action fixed when a proposal is created and applied by `execute`
+/// once the proposal has been approved.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProposalAction {
+    /// Set the threshold of the proposal's own signer group. There is no
+    /// way to target another id, so a proposal only ever reconfigures the
+    /// group that approved it.
+    SetThreshold(u32),
+}
+
 /// Namespace symbol used as the first topic of every event this contract emits.
 const CONTRACT_NS: Symbol = symbol_short!("multi");
 /// Naming convention: `snake_case` action names in topic[1].
@@ -44,6 +85,8 @@ pub enum DataKey {
     EscrowStep(Address, Address),
     Threshold(Symbol),
     Signers(Symbol),
+    Proposal(Symbol),
+    Action(Symbol),
 }
 
 // ---------------------------------------------------------------------------
@@ -166,6 +209,34 @@ impl MultiPartyAuthContract {
         Self::multi_sig_transfer(env, signers, to, amount);
     }
 
+    /// N-of-N authorization restricted to the signer set stored for
+    /// `proposal_id`.
+    ///
+    /// Unlike [`Self::multi_sig_transfer`], which accepts any addresses,
+    /// every entry in `signers` must already be in `DataKey::Signers`.
+    /// Membership is checked for the whole list before any `require_auth`,
+    /// so an outsider is rejected with "Not an authorized signer" no matter
+    /// where it appears.
+    pub fn multi_auth_restricted(env: Env, proposal_id: Symbol, signers: Vec<Address>) {
+        let valid_signers = Self::get_signers(env.clone(), proposal_id.clone());
+        for signer in signers.iter() {
+            if !valid_signers.contains(&signer) {
+                panic!("Not an authorized signer");
+            }
+        }
+        for signer in signers.iter() {
+            signer.require_auth();
+        }
+
+        env.events().publish(
+            (CONTRACT_NS, ACTION_AUDIT, proposal_id),
+            AuditTrailEventData {
+                details: symbol_short!("msig_rst"),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+
     /// M-of-N threshold approval.
     ///
     /// Requires at least `threshold` parties from the stored valid-signers
@@ -188,6 +259,17 @@ impl MultiPartyAuthContract {
                 Vec::new(&env)
             });
 
+        let proposal_key = DataKey::Proposal(proposal_id.clone());
+        let mut proposal: Option<Proposal> = env.storage().instance().get(&proposal_key);
+        if let Some(p) = &proposal {
+            if p.approved {
+                panic!("Proposal already approved");
+            }
+            if p.expired || env.ledger().timestamp() >= p.expires_at {
+                panic!("Proposal expired");
+            }
+        }
+
         let mut valid_approval_count = 0u32;
 
         for approver in approvers.iter() {
@@ -203,6 +285,11 @@ impl MultiPartyAuthContract {
             panic!("Threshold not met");
         }
 
+        if let Some(p) = proposal.as_mut() {
+            p.approved = true;
+            env.storage().instance().set(&proposal_key, p);
+        }
+
         // Audit trail for proposal approval
         env.events().publish(
             (CONTRACT_NS, ACTION_AUDIT, proposal_id),
@@ -258,31 +345,168 @@ impl MultiPartyAuthContract {
     }
 
     /// Test helper: store threshold and valid-signers for a proposal.
+    ///
+    /// Panics with "Duplicate signer" if any address repeats, since a repeat
+    /// would leave fewer real parties than `threshold` assumes, and with
+    /// "Threshold exceeds signer count" if `threshold` is above that count.
+    /// Like [`Self::create_proposal`] it panics with "Proposal already
+    /// exists" for an id that is already in use, so it cannot reconfigure
+    /// an existing group.
     pub fn setup_proposal(env: Env, proposal_id: Symbol, threshold: u32, signers: Vec<Address>) {
+        require_unused_id(&env, &proposal_id);
+        store_signer_group(&env, proposal_id.clone(), threshold, signers);
+    }
+
+    /// Create a proposal with a title and link, together with its own
+    /// signer group and threshold.
+    ///
+    /// `creator` must authorize the call. The id must be unused: creating a
+    /// proposal whose id already has a `Proposal` record, signers or a
+    /// threshold panics with "Proposal already exists", so an existing
+    /// group can never be reconfigured by creating a proposal over it.
+    /// The metadata is write-once; there is no setter. `threshold` must be
+    /// at least 1, and `expires_at` must be a ledger timestamp in the
+    /// future. From then on `proposal_approval` refuses the proposal.
+    ///
+    /// `action`, if any, is fixed here and can never be attached or changed
+    /// later, so the signers' approval always covers exactly what
+    /// [`Self::execute`] will run.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_proposal(
+        env: Env,
+        creator: Address,
+        proposal_id: Symbol,
+        threshold: u32,
+        signers: Vec<Address>,
+        title: String,
+        uri: String,
+        expires_at: u64,
+        action: Option<ProposalAction>,
+    ) {
+        creator.require_auth();
+
+        require_unused_id(&env, &proposal_id);
+        if threshold == 0 {
+            panic!("Threshold must be at least 1");
+        }
+        let now = env.ledger().timestamp();
+        if expires_at <= now {
+            panic!("Expiry must be in the future");
+        }
+
+        store_signer_group(&env, proposal_id.clone(), threshold, signers);
+
+        let proposal = Proposal {
+            title,
+            uri,
+            created_at: now,
+            expires_at,
+            approved: false,
+            executed: false,
+            expired: false,
+        };
         env.storage()
             .instance()
-            .set(&DataKey::Threshold(proposal_id.clone()), &threshold);
-        env.storage()
+            .set(&DataKey::Proposal(proposal_id.clone()), &proposal);
+        if let Some(action) = action {
+            env.storage()
+                .instance()
+                .set(&DataKey::Action(proposal_id), &action);
+        }
+    }
+
+    /// Mark an unexecuted proposal whose expiry has passed as expired.
+    ///
+    /// Anyone may call this: it only records what the clock already says,
+    /// making the terminal state explicit for indexers and UIs. An approved
+    /// proposal can still expire if nobody executed it in time; only an
+    /// executed one is refused, with "Proposal already executed".
+    pub fn expire_proposal(env: Env, proposal_id: Symbol) {
+        let key = DataKey::Proposal(proposal_id.clone());
+        let mut proposal: Proposal = env
+            .storage()
             .instance()
-            .set(&DataKey::Signers(proposal_id.clone()), &signers);
+            .get(&key)
+            .unwrap_or_else(|| panic!("Proposal not found"));
+
+        if proposal.executed {
+            panic!("Proposal already executed");
+        }
+        if proposal.expired {
+            panic!("Proposal already expired");
+        }
+        if env.ledger().timestamp() < proposal.expires_at {
+            panic!("Proposal not yet expired");
+        }
+
+        proposal.expired = true;
+        env.storage().instance().set(&key, &proposal);
 
-        // Admin-style setup event
         env.events().publish(
             (CONTRACT_NS, ACTION_ADMIN, proposal_id),
             AdminActionEventData {
-                action: symbol_short!("prop_set"),
+                action: symbol_short!("expire"),
                 timestamp: env.ledger().timestamp(),
             },
         );
     }
 
+    /// Return the metadata recorded by [`Self::create_proposal`], if any.
+    pub fn get_proposal(env: Env, proposal_id: Symbol) -> Option<Proposal> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Proposal(proposal_id))
+    }
+
+    /// Return the action `proposal_id` was created with. It stays readable
+    /// after `execute`; the proposal's `executed` flag records that it ran.
+    pub fn get_action(env: Env, proposal_id: Symbol) -> Option<ProposalAction> {
+        env.storage().instance().get(&DataKey::Action(proposal_id))
+    }
+
+    /// Apply the action of an approved proposal, exactly once.
+    ///
+    /// Anyone may call this; approval already happened in
+    /// `proposal_approval`. The proposal's `executed` flag is set, and a
+    /// second call panics with "Proposal already executed". Values are
+    /// validated against the configuration at execution time, since signers
+    /// may have changed since the action was proposed: `SetThreshold` must
+    /// stay in `1..=signers.len()` and emits `threshold_changed`. Once a
+    /// group exists, this is the only way to change its threshold.
+    ///
+    /// Approval does not outlive the deadline: once `expires_at` has passed,
+    /// or the proposal was marked expired, execution panics with "Proposal
+    /// expired".
+    pub fn execute(env: Env, proposal_id: Symbol) {
+        let mut proposal = Self::get_proposal(env.clone(), proposal_id.clone())
+            .unwrap_or_else(|| panic!("Proposal not found"));
+        if !proposal.approved {
+            panic!("Proposal not approved");
+        }
+        if proposal.executed {
+            panic!("Proposal already executed");
+        }
+        if proposal.expired || env.ledger().timestamp() >= proposal.expires_at {
+            panic!("Proposal expired");
+        }
+
+        let action: ProposalAction = Self::get_action(env.clone(), proposal_id.clone())
+            .unwrap_or_else(|| panic!("No action to execute"));
+
+        match action {
+            ProposalAction::SetThreshold(threshold) => {
+                apply_threshold(&env, proposal_id.clone(), threshold);
+            }
+        }
+
+        proposal.executed = true;
+        env.storage()
+            .instance()
+            .set(&DataKey::Proposal(proposal_id), &proposal);
+    }
+
     /// Add `new_signer` to the signer set for `proposal_id`.
-    pub fn add_signer(
-        env: Env,
-        caller: Address,
-        proposal_id: Symbol,
-        new_signer: Address,
-    ) {
+    pub fn add_signer(env: Env, caller: Address, proposal_id: Symbol, new_signer: Address) {
         caller.require_auth();
 
         let key = DataKey::Signers(proposal_id.clone());
@@ -304,6 +528,7 @@ impl MultiPartyAuthContract {
 
         signers.push_back(new_signer.clone());
         env.storage().instance().set(&key, &signers);
+        publish_signer_change(&env, "signer_added", new_signer, proposal_id.clone());
 
         env.events().publish(
             (CONTRACT_NS, ACTION_ADMIN, proposal_id),
@@ -355,49 +580,17 @@ impl MultiPartyAuthContract {
             }
         }
         env.storage().instance().set(&signers_key, &updated);
-
-        env.events().publish(
-            (CONTRACT_NS, ACTION_ADMIN, proposal_id),
-            AdminActionEventData {
-                action: symbol_short!("rm_sgn"),
-                timestamp: env.ledger().timestamp(),
-            },
+        publish_signer_change(
+            &env,
+            "signer_removed",
+            signer_to_remove,
+            proposal_id.clone(),
         );
-    }
-
-    /// Update the approval threshold for `proposal_id`.
-    pub fn set_threshold(
-        env: Env,
-        caller: Address,
-        proposal_id: Symbol,
-        new_threshold: u32,
-    ) {
-        caller.require_auth();
-
-        let signers: Vec<Address> = env
-            .storage()
-            .instance()
-            .get(&DataKey::Signers(proposal_id.clone()))
-            .unwrap_or_else(|| Vec::new(&env));
-
-        if !signers.contains(&caller) {
-            panic!("Caller is not a current signer");
-        }
-        if new_threshold == 0 {
-            panic!("Threshold must be at least 1");
-        }
-        if new_threshold > signers.len() {
-            panic!("Threshold exceeds signer count");
-        }
-
-        env.storage()
-            .instance()
-            .set(&DataKey::Threshold(proposal_id.clone()), &new_threshold);
 
         env.events().publish(
             (CONTRACT_NS, ACTION_ADMIN, proposal_id),
             AdminActionEventData {
-                action: symbol_short!("set_thr"),
+                action: symbol_short!("rm_sgn"),
                 timestamp: env.ledger().timestamp(),
             },
         );
@@ -440,6 +633,8 @@ impl MultiPartyAuthContract {
             }
         }
         env.storage().instance().set(&key, &updated);
+        publish_signer_change(&env, "signer_removed", old_signer, proposal_id.clone());
+        publish_signer_change(&env, "signer_added", new_signer, proposal_id.clone());
 
         env.events().publish(
             (CONTRACT_NS, ACTION_ADMIN, proposal_id),
@@ -458,6 +653,11 @@ impl MultiPartyAuthContract {
             .unwrap_or_else(|| Vec::new(&env))
     }
 
+    /// Return `true` if `addr` is in the signer set for `proposal_id`.
+    pub fn is_signer(env: Env, proposal_id: Symbol, addr: Address) -> bool {
+        Self::get_signers(env, proposal_id).contains(&addr)
+    }
+
     /// Return the current threshold for `proposal_id`.
     pub fn get_threshold(env: Env, proposal_id: Symbol) -> u32 {
         env.storage()
@@ -605,6 +805,120 @@ impl MultiPartyAuthContract {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Signer groups
+// ---------------------------------------------------------------------------
+
+/// Panic with "Proposal already exists" if `proposal_id` already has a
+/// proposal record, signers or a threshold.
+fn require_unused_id(env: &Env, proposal_id: &Symbol) {
+    let storage = env.storage().instance();
+    if storage.has(&DataKey::Proposal(proposal_id.clone()))
+        || storage.has(&DataKey::Signers(proposal_id.clone()))
+        || storage.has(&DataKey::Threshold(proposal_id.clone()))
+    {
+        panic!("Proposal already exists");
+    }
+}
+
+/// Validate and store the threshold and signer list for `proposal_id`.
+///
+/// Panics with "Duplicate signer" if any address repeats, since a repeat
+/// would leave fewer real parties than `threshold` assumes, and with
+/// "Threshold exceeds signer count" if `threshold` is above that count.
+fn store_signer_group(env: &Env, proposal_id: Symbol, threshold: u32, signers: Vec<Address>) {
+    for i in 0..signers.len() {
+        let signer = signers.get_unchecked(i);
+        for j in (i + 1)..signers.len() {
+            if signers.get_unchecked(j) == signer {
+                panic!("Duplicate signer");
+            }
+        }
+    }
+    if threshold > signers.len() {
+        panic!("Threshold exceeds signer count");
+    }
+
+    env.storage()
+        .instance()
+        .set(&DataKey::Threshold(proposal_id.clone()), &threshold);
+    env.storage()
+        .instance()
+        .set(&DataKey::Signers(proposal_id.clone()), &signers);
+
+    // Admin-style setup event
+    env.events().publish(
+        (CONTRACT_NS, ACTION_ADMIN, proposal_id),
+        AdminActionEventData {
+            action: symbol_short!("prop_set"),
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+}
+
+/// Store a new threshold for `proposal_id`'s group.
+///
+/// Deliberately not a contract entry point: the only caller is
+/// `execute`, so a threshold changes only with the group's M-of-N approval,
+/// never on a single signer's say-so. Panics with "Threshold must be at
+/// least 1" or "Threshold exceeds signer count".
+fn apply_threshold(env: &Env, proposal_id: Symbol, threshold: u32) {
+    let signers: Vec<Address> = env
+        .storage()
+        .instance()
+        .get(&DataKey::Signers(proposal_id.clone()))
+        .unwrap_or_else(|| Vec::new(env));
+
+    if threshold == 0 {
+        panic!("Threshold must be at least 1");
+    }
+    if threshold > signers.len() {
+        panic!("Threshold exceeds signer count");
+    }
+
+    env.storage()
+        .instance()
+        .set(&DataKey::Threshold(proposal_id.clone()), &threshold);
+    publish_threshold_changed(env, proposal_id.clone(), threshold);
+
+    env.events().publish(
+        (CONTRACT_NS, ACTION_ADMIN, proposal_id),
+        AdminActionEventData {
+            action: symbol_short!("set_thr"),
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Configuration events
+// ---------------------------------------------------------------------------
+//
+// Alongside the namespaced admin events, every change to a signer set or
+// threshold emits a top-level event an indexer can follow to track the
+// configuration over time:
+//
+//   ("signer_added", addr)    data: proposal_id
+//   ("signer_removed", addr)  data: proposal_id
+//   ("threshold_changed",)    data: ThresholdChangedEventData
+
+/// `name` is `"signer_added"` or `"signer_removed"`. The signer goes in a
+/// topic so an indexer can filter for one address across all groups.
+fn publish_signer_change(env: &Env, name: &str, signer: Address, proposal_id: Symbol) {
+    env.events()
+        .publish((Symbol::new(env, name), signer), proposal_id);
+}
+
+fn publish_threshold_changed(env: &Env, proposal_id: Symbol, threshold: u32) {
+    env.events().publish(
+        (Symbol::new(env, "threshold_changed"),),
+        ThresholdChangedEventData {
+            proposal_id,
+            threshold,
+        },
+    );
+}
+
 // ---------------------------------------------------------------------------
 // Byte utilities
 // ---------------------------------------------------------------------------
@@ -2,9 +2,10 @@ extern crate std;
 
 use super::*;
 use soroban_sdk::{
-    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation},
-    Address, Bytes, Env, IntoVal, Symbol, Vec,
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events as _, Ledger},
+    Address, Bytes, Env, IntoVal, String, Symbol, TryFromVal, Vec,
 };
+use soroban_validation::test_events::EventList;
 
 // ---------------------------------------------------------------------------
 // Auth vector: encode / decode / validate tests
@@ -354,6 +355,352 @@ fn test_proposal_approval_fails_invalid_signer() {
     client.proposal_approval(&proposal_id, &approvers);
 }
 
+#[test]
+fn test_setup_proposal_distinct_signers_succeeds() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, MultiPartyAuthContract);
+    let client = MultiPartyAuthContractClient::new(&env, &contract_id);
+
+    let signers = Vec::from_array(
+        &env,
+        [
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+        ],
+    );
+    let proposal_id = Symbol::new(&env, "distinct");
+
+    client.setup_proposal(&proposal_id, &3u32, &signers);
+    assert_eq!(client.get_signers(&proposal_id), signers);
+    assert_eq!(client.get_threshold(&proposal_id), 3);
+}
+
+#[test]
+#[should_panic(expected = "Duplicate signer")]
+fn test_setup_proposal_rejects_duplicate_signer() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, MultiPartyAuthContract);
+    let client = MultiPartyAuthContractClient::new(&env, &contract_id);
+
+    let signer1 = Address::generate(&env);
+    let signer2 = Address::generate(&env);
+    // Three entries, but only two distinct parties behind a 3-of-N threshold.
+    let signers = Vec::from_array(&env, [signer1.clone(), signer2, signer1]);
+
+    client.setup_proposal(&Symbol::new(&env, "dup"), &3u32, &signers);
+}
+
+#[test]
+fn test_create_proposal_metadata_round_trips() {
+    let env = Env::default();
+    env.ledger().with_mut(|li| li.timestamp = 5_000);
+    let contract_id = env.register_contract(None, MultiPartyAuthContract);
+    let client = MultiPartyAuthContractClient::new(&env, &contract_id);
+
+    let signers = Vec::from_array(&env, [Address::generate(&env), Address::generate(&env)]);
+    let proposal_id = Symbol::new(&env, "meta");
+    let title = String::from_str(&env, "Raise treasury limit");
+    let uri = String::from_str(&env, "https://forum.example.org/t/42");
+
+    assert_eq!(client.get_proposal(&proposal_id), None);
+    env.mock_all_auths();
+    let creator = Address::generate(&env);
+    client.create_proposal(
+        &creator,
+        &proposal_id,
+        &2u32,
+        &signers,
+        &title,
+        &uri,
+        &10_000,
+        &None,
+    );
+
+    let proposal = client.get_proposal(&proposal_id).unwrap();
+    assert_eq!(proposal.title, title);
+    assert_eq!(proposal.uri, uri);
+    assert_eq!(proposal.created_at, 5_000);
+    assert_eq!(proposal.expires_at, 10_000);
+    assert!(!proposal.approved && !proposal.executed && !proposal.expired);
+    assert_eq!(client.get_signers(&proposal_id), signers);
+    assert_eq!(client.get_threshold(&proposal_id), 2);
+}
+
+#[test]
+fn test_proposal_metadata_unchanged_by_signer_updates() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, MultiPartyAuthContract);
+    let client = MultiPartyAuthContractClient::new(&env, &contract_id);
+
+    let signer1 = Address::generate(&env);
+    let signers = Vec::from_array(&env, [signer1.clone(), Address::generate(&env)]);
+    let proposal_id = Symbol::new(&env, "fixed");
+    client.create_proposal(
+        &signer1,
+        &proposal_id,
+        &2u32,
+        &signers,
+        &String::from_str(&env, "Original title"),
+        &String::from_str(&env, "ipfs://original"),
+        &1_000,
+        &None,
+    );
+    let before = client.get_proposal(&proposal_id).unwrap();
+
+    client.add_signer(&signer1, &proposal_id, &Address::generate(&env));
+
+    assert_eq!(client.get_proposal(&proposal_id).unwrap(), before);
+}
+
+#[test]
+#[should_panic(expected = "Proposal already exists")]
+fn test_create_proposal_twice_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, MultiPartyAuthContract);
+    let client = MultiPartyAuthContractClient::new(&env, &contract_id);
+
+    let creator = Address::generate(&env);
+    let signers = Vec::from_array(&env, [Address::generate(&env)]);
+    let proposal_id = Symbol::new(&env, "once");
+    let title = String::from_str(&env, "First");
+    let uri = String::from_str(&env, "ipfs://first");
+    client.create_proposal(
+        &creator,
+        &proposal_id,
+        &1u32,
+        &signers,
+        &title,
+        &uri,
+        &1_000,
+        &None,
+    );
+
+    let new_title = String::from_str(&env, "Rewritten");
+    client.create_proposal(
+        &creator,
+        &proposal_id,
+        &1u32,
+        &signers,
+        &new_title,
+        &uri,
+        &1_000,
+        &None,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Proposal already exists")]
+fn test_create_proposal_cannot_overwrite_existing_group() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, MultiPartyAuthContract);
+    let client = MultiPartyAuthContractClient::new(&env, &contract_id);
+
+    // A signer group with no `Proposal` record yet
+    let group = Symbol::new(&env, "treasury");
+    let members = Vec::from_array(&env, [Address::generate(&env), Address::generate(&env)]);
+    client.setup_proposal(&group, &2u32, &members);
+
+    // An outsider tries to replace it with a 1-of-1 group of their own
+    let attacker = Address::generate(&env);
+    client.create_proposal(
+        &attacker,
+        &group,
+        &1u32,
+        &Vec::from_array(&env, [attacker.clone()]),
+        &String::from_str(&env, "Takeover"),
+        &String::from_str(&env, "ipfs://takeover"),
+        &1_000,
+        &None,
+    );
+}
+
+#[test]
+fn test_setup_proposal_cannot_overwrite_created_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, MultiPartyAuthContract);
+    let client = MultiPartyAuthContractClient::new(&env, &contract_id);
+
+    let id = Symbol::new(&env, "budget");
+    let members = Vec::from_array(&env, [Address::generate(&env), Address::generate(&env)]);
+    client.create_proposal(
+        &Address::generate(&env),
+        &id,
+        &2u32,
+        &members,
+        &String::from_str(&env, "Budget"),
+        &String::from_str(&env, "ipfs://budget"),
+        &1_000,
+        &None,
+    );
+
+    let attacker = Address::generate(&env);
+    let result = client.try_setup_proposal(&id, &1u32, &Vec::from_array(&env, [attacker]));
+    assert!(result.is_err());
+    assert_eq!(client.get_threshold(&id), 2);
+    assert_eq!(client.get_signers(&id), members);
+}
+
+#[test]
+fn test_setup_proposal_cannot_overwrite_existing_group() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, MultiPartyAuthContract);
+    let client = MultiPartyAuthContractClient::new(&env, &contract_id);
+
+    let group = Symbol::new(&env, "treasury");
+    let members = Vec::from_array(&env, [Address::generate(&env), Address::generate(&env)]);
+    client.setup_proposal(&group, &2u32, &members);
+
+    // A second setup on the same id would lower the threshold to 1-of-1
+    let attacker = Address::generate(&env);
+    let result =
+        client.try_setup_proposal(&group, &1u32, &Vec::from_array(&env, [attacker.clone()]));
+    assert!(result.is_err());
+    assert_eq!(client.get_threshold(&group), 2);
+    assert_eq!(client.get_signers(&group), members);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
+fn test_create_proposal_requires_creator_auth() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, MultiPartyAuthContract);
+    let client = MultiPartyAuthContractClient::new(&env, &contract_id);
+
+    client.create_proposal(
+        &Address::generate(&env),
+        &Symbol::new(&env, "noauth"),
+        &1u32,
+        &Vec::from_array(&env, [Address::generate(&env)]),
+        &String::from_str(&env, "Unsigned"),
+        &String::from_str(&env, "ipfs://unsigned"),
+        &1_000,
+        &None,
+    );
+}
+
+fn create_expiring_proposal(
+    env: &Env,
+    client: &MultiPartyAuthContractClient,
+    proposal_id: &Symbol,
+    expires_at: u64,
+) -> Vec<Address> {
+    let signers = Vec::from_array(env, [Address::generate(env), Address::generate(env)]);
+    client.create_proposal(
+        &signers.get(0).unwrap(),
+        proposal_id,
+        &2u32,
+        &signers,
+        &String::from_str(env, "Expiring"),
+        &String::from_str(env, "ipfs://expiring"),
+        &expires_at,
+        &None,
+    );
+    signers
+}
+
+#[test]
+fn test_expired_proposal_cannot_be_approved() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, MultiPartyAuthContract);
+    let client = MultiPartyAuthContractClient::new(&env, &contract_id);
+
+    let proposal_id = Symbol::new(&env, "stale");
+    let signers = create_expiring_proposal(&env, &client, &proposal_id, 1_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.expire_proposal(&proposal_id);
+
+    let proposal = client.get_proposal(&proposal_id).unwrap();
+    assert!(proposal.expired);
+    assert!(!proposal.approved);
+
+    // A partial approval and a threshold-meeting one (which would approve)
+    // are both refused.
+    let one = Vec::from_array(&env, [signers.get(0).unwrap()]);
+    assert!(client.try_proposal_approval(&proposal_id, &one).is_err());
+    assert!(client
+        .try_proposal_approval(&proposal_id, &signers)
+        .is_err());
+    assert!(!client.get_proposal(&proposal_id).unwrap().approved);
+}
+
+#[test]
+#[should_panic(expected = "Proposal expired")]
+fn test_past_expiry_approval_fails_before_expire_is_called() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, MultiPartyAuthContract);
+    let client = MultiPartyAuthContractClient::new(&env, &contract_id);
+
+    let proposal_id = Symbol::new(&env, "late");
+    let signers = create_expiring_proposal(&env, &client, &proposal_id, 1_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_001);
+    client.proposal_approval(&proposal_id, &signers);
+}
+
+#[test]
+#[should_panic(expected = "Proposal not yet expired")]
+fn test_expire_proposal_before_expiry_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, MultiPartyAuthContract);
+    let client = MultiPartyAuthContractClient::new(&env, &contract_id);
+
+    let proposal_id = Symbol::new(&env, "fresh");
+    create_expiring_proposal(&env, &client, &proposal_id, 1_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 999);
+    client.expire_proposal(&proposal_id);
+}
+
+#[test]
+fn test_approved_proposal_expires_if_not_executed() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, MultiPartyAuthContract);
+    let client = MultiPartyAuthContractClient::new(&env, &contract_id);
+
+    let signers = propose_threshold_change(&env, &client, &ProposalAction::SetThreshold(3));
+    let proposal_id = Symbol::new(&env, "thr_chg");
+    client.proposal_approval(&proposal_id, &signers);
+    let proposal = client.get_proposal(&proposal_id).unwrap();
+    assert!(proposal.approved && !proposal.executed);
+
+    // The approval is stale once the deadline passes
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    assert!(client.try_execute(&proposal_id).is_err());
+    assert_eq!(client.get_threshold(&proposal_id), 2);
+
+    client.expire_proposal(&proposal_id);
+    let proposal = client.get_proposal(&proposal_id).unwrap();
+    assert!(proposal.approved && proposal.expired && !proposal.executed);
+}
+
+#[test]
+#[should_panic(expected = "Proposal already executed")]
+fn test_expire_proposal_after_execution_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, MultiPartyAuthContract);
+    let client = MultiPartyAuthContractClient::new(&env, &contract_id);
+
+    let signers = propose_threshold_change(&env, &client, &ProposalAction::SetThreshold(3));
+    let proposal_id = Symbol::new(&env, "thr_chg");
+    client.proposal_approval(&proposal_id, &signers);
+    client.execute(&proposal_id);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    client.expire_proposal(&proposal_id);
+}
+
 #[test]
 fn test_sequential_auth_escrow() {
     let env = Env::default();
@@ -446,3 +793,324 @@ fn test_sequential_auth_escrow_unauthorized_step2() {
 
     client.sequential_auth_escrow(&buyer, &seller, &1000i128);
 }
+
+// ---------------------------------------------------------------------------
+// Restricted N-of-N authorization
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_multi_auth_restricted_all_members() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = MultiPartyAuthContractClient::new(&env, &env.register(MultiPartyAuthContract, ()));
+
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    let c = Address::generate(&env);
+    let group = Symbol::new(&env, "team");
+    client.setup_proposal(
+        &group,
+        &2u32,
+        &Vec::from_array(&env, [a.clone(), b.clone(), c.clone()]),
+    );
+
+    let signers = Vec::from_array(&env, [a.clone(), c.clone()]);
+    client.multi_auth_restricted(&group, &signers);
+
+    let auths = env.auths();
+    assert_eq!(auths.len(), 2);
+    assert_eq!(auths[0].0, a);
+    assert_eq!(auths[1].0, c);
+}
+
+#[test]
+#[should_panic(expected = "Not an authorized signer")]
+fn test_multi_auth_restricted_rejects_outsider() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = MultiPartyAuthContractClient::new(&env, &env.register(MultiPartyAuthContract, ()));
+
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    let group = Symbol::new(&env, "team");
+    client.setup_proposal(
+        &group,
+        &1u32,
+        &Vec::from_array(&env, [a.clone(), b.clone()]),
+    );
+
+    let outsider = Address::generate(&env);
+    client.multi_auth_restricted(&group, &Vec::from_array(&env, [a, outsider]));
+}
+
+// ---------------------------------------------------------------------------
+// Configuration views
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_config_views_match_setup() {
+    let env = Env::default();
+    let client = MultiPartyAuthContractClient::new(&env, &env.register(MultiPartyAuthContract, ()));
+
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    let c = Address::generate(&env);
+    let signers = Vec::from_array(&env, [a.clone(), b.clone(), c.clone()]);
+    let group = Symbol::new(&env, "team");
+    client.setup_proposal(&group, &2u32, &signers);
+
+    assert_eq!(client.get_signers(&group), signers);
+    assert_eq!(client.get_threshold(&group), 2);
+
+    assert!(client.is_signer(&group, &a));
+    assert!(client.is_signer(&group, &c));
+    assert!(!client.is_signer(&group, &Address::generate(&env)));
+    // Membership is per proposal id
+    assert!(!client.is_signer(&Symbol::new(&env, "other"), &a));
+}
+
+// ---------------------------------------------------------------------------
+// Threshold change via proposal
+// ---------------------------------------------------------------------------
+
+/// A 2-of-3 proposal `thr_chg` carrying `action` for its own group.
+fn propose_threshold_change(
+    env: &Env,
+    client: &MultiPartyAuthContractClient,
+    action: &ProposalAction,
+) -> Vec<Address> {
+    let signers = Vec::from_array(
+        env,
+        [
+            Address::generate(env),
+            Address::generate(env),
+            Address::generate(env),
+        ],
+    );
+    let proposal_id = Symbol::new(env, "thr_chg");
+    client.create_proposal(
+        &signers.get(0).unwrap(),
+        &proposal_id,
+        &2u32,
+        &signers,
+        &String::from_str(env, "Require all three signers"),
+        &String::from_str(env, "ipfs://thr"),
+        &1_000,
+        &Some(action.clone()),
+    );
+    signers
+}
+
+#[test]
+fn test_threshold_change_via_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(MultiPartyAuthContract, ());
+    let client = MultiPartyAuthContractClient::new(&env, &contract_id);
+
+    let proposal_id = Symbol::new(&env, "thr_chg");
+    let action = ProposalAction::SetThreshold(3);
+    let signers = propose_threshold_change(&env, &client, &action);
+    assert_eq!(client.get_action(&proposal_id), Some(action));
+
+    let approvers = Vec::from_array(&env, [signers.get(0).unwrap(), signers.get(2).unwrap()]);
+    client.proposal_approval(&proposal_id, &approvers);
+    client.execute(&proposal_id);
+
+    let (_topics, data) = config_event(&env, "threshold_changed");
+    assert_eq!(
+        ThresholdChangedEventData::try_from_val(&env, &data).unwrap(),
+        ThresholdChangedEventData {
+            proposal_id: proposal_id.clone(),
+            threshold: 3,
+        }
+    );
+
+    assert_eq!(client.get_threshold(&proposal_id), 3);
+    // The action ran once and cannot be replayed
+    assert!(client.get_proposal(&proposal_id).unwrap().executed);
+    assert!(client.try_execute(&proposal_id).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Threshold exceeds signer count")]
+fn test_threshold_change_above_signer_count_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = MultiPartyAuthContractClient::new(&env, &env.register(MultiPartyAuthContract, ()));
+
+    let action = ProposalAction::SetThreshold(4);
+    let signers = propose_threshold_change(&env, &client, &action);
+
+    let proposal_id = Symbol::new(&env, "thr_chg");
+    client.proposal_approval(&proposal_id, &signers);
+    client.execute(&proposal_id);
+}
+
+#[test]
+#[should_panic(expected = "Proposal not approved")]
+fn test_threshold_change_requires_approval() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = MultiPartyAuthContractClient::new(&env, &env.register(MultiPartyAuthContract, ()));
+
+    let action = ProposalAction::SetThreshold(3);
+    propose_threshold_change(&env, &client, &action);
+    client.execute(&Symbol::new(&env, "thr_chg"));
+}
+
+#[test]
+#[should_panic(expected = "No action to execute")]
+fn test_execute_without_action_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = MultiPartyAuthContractClient::new(&env, &env.register(MultiPartyAuthContract, ()));
+
+    // Actions can only be supplied at creation; this proposal has none
+    let proposal_id = Symbol::new(&env, "plain");
+    let signers = create_expiring_proposal(&env, &client, &proposal_id, 1_000);
+    assert_eq!(client.get_action(&proposal_id), None);
+
+    client.proposal_approval(&proposal_id, &signers);
+    client.execute(&proposal_id);
+}
+
+#[test]
+fn test_threshold_change_cannot_touch_another_group() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = MultiPartyAuthContractClient::new(&env, &env.register(MultiPartyAuthContract, ()));
+
+    let victim = Symbol::new(&env, "treasury");
+    let members = Vec::from_array(
+        &env,
+        [
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+        ],
+    );
+    client.setup_proposal(&victim, &3u32, &members);
+
+    // An outsider runs a proposal of their own through the full flow
+    let attacker = Address::generate(&env);
+    let accomplice = Address::generate(&env);
+    let own = Symbol::new(&env, "x");
+    client.create_proposal(
+        &attacker,
+        &own,
+        &1u32,
+        &Vec::from_array(&env, [attacker.clone(), accomplice]),
+        &String::from_str(&env, "Lower threshold"),
+        &String::from_str(&env, "ipfs://x"),
+        &1_000,
+        &Some(ProposalAction::SetThreshold(2)),
+    );
+    client.proposal_approval(&own, &Vec::from_array(&env, [attacker]));
+    client.execute(&own);
+
+    // Only the proposal's own group changed
+    assert_eq!(client.get_threshold(&own), 2);
+    assert_eq!(client.get_threshold(&victim), 3);
+}
+
+// ---------------------------------------------------------------------------
+// Signer-rotation event stream
+// ---------------------------------------------------------------------------
+
+/// The single event of the last invocation whose first topic is `name`,
+/// returned as `(topics, data)`.
+fn config_event(env: &Env, name: &str) -> (Vec<soroban_sdk::Val>, soroban_sdk::Val) {
+    let name = Symbol::new(env, name);
+    let mut found = None;
+    for (_id, topics, data) in EventList::new(env, env.events().all()).iter() {
+        if Symbol::try_from_val(env, &topics.get(0).unwrap()).unwrap() == name {
+            assert!(found.is_none(), "event emitted more than once");
+            found = Some((topics, data));
+        }
+    }
+    found.expect("event not emitted")
+}
+
+fn assert_signer_event(env: &Env, name: &str, signer: &Address, group: &Symbol) {
+    let (topics, data) = config_event(env, name);
+    assert_eq!(topics.len(), 2);
+    assert_eq!(
+        Address::try_from_val(env, &topics.get(1).unwrap()).unwrap(),
+        *signer
+    );
+    assert_eq!(Symbol::try_from_val(env, &data).unwrap(), *group);
+}
+
+fn setup_group(env: &Env, client: &MultiPartyAuthContractClient) -> (Symbol, Vec<Address>) {
+    let group = Symbol::new(env, "team");
+    let signers = Vec::from_array(
+        env,
+        [
+            Address::generate(env),
+            Address::generate(env),
+            Address::generate(env),
+        ],
+    );
+    client.setup_proposal(&group, &2u32, &signers);
+    (group, signers)
+}
+
+#[test]
+fn test_add_signer_emits_signer_added() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = MultiPartyAuthContractClient::new(&env, &env.register(MultiPartyAuthContract, ()));
+    let (group, signers) = setup_group(&env, &client);
+
+    let newcomer = Address::generate(&env);
+    client.add_signer(&signers.get(0).unwrap(), &group, &newcomer);
+    assert_signer_event(&env, "signer_added", &newcomer, &group);
+}
+
+#[test]
+fn test_remove_signer_emits_signer_removed() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = MultiPartyAuthContractClient::new(&env, &env.register(MultiPartyAuthContract, ()));
+    let (group, signers) = setup_group(&env, &client);
+
+    let leaving = signers.get(2).unwrap();
+    client.remove_signer(&signers.get(0).unwrap(), &group, &leaving);
+    assert_signer_event(&env, "signer_removed", &leaving, &group);
+}
+
+#[test]
+fn test_rotate_signer_emits_removed_and_added() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = MultiPartyAuthContractClient::new(&env, &env.register(MultiPartyAuthContract, ()));
+    let (group, signers) = setup_group(&env, &client);
+
+    let old = signers.get(1).unwrap();
+    let new = Address::generate(&env);
+    client.rotate_signer(&signers.get(0).unwrap(), &group, &old, &new);
+    assert_signer_event(&env, "signer_removed", &old, &group);
+    assert_signer_event(&env, "signer_added", &new, &group);
+}
+
+#[test]
+fn test_executed_threshold_change_emits_threshold_changed() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = MultiPartyAuthContractClient::new(&env, &env.register(MultiPartyAuthContract, ()));
+    let signers = propose_threshold_change(&env, &client, &ProposalAction::SetThreshold(3));
+    let proposal_id = Symbol::new(&env, "thr_chg");
+
+    client.proposal_approval(&proposal_id, &signers);
+    client.execute(&proposal_id);
+    let (topics, data) = config_event(&env, "threshold_changed");
+    assert_eq!(topics.len(), 1);
+    assert_eq!(
+        ThresholdChangedEventData::try_from_val(&env, &data).unwrap(),
+        ThresholdChangedEventData {
+            proposal_id,
+            threshold: 3,
+        }
+    );
+}
@@ -40,6 +40,11 @@ pub const MIN_DELAY: u64 = 60;
 /// Maximum timelock delay: 7 days.
 pub const MAX_DELAY: u64 = 604_800;
 
+/// `touch` extends the instance TTL once it drops below this (~1 day).
+pub const TTL_THRESHOLD: u32 = 17_280;
+/// `touch` extends the instance TTL to this many ledgers (~7 days).
+pub const TTL_EXTEND: u32 = 120_960;
+
 // ---------------------------------------------------------------------------
 // Storage keys
 // ---------------------------------------------------------------------------
@@ -52,6 +57,10 @@ pub enum DataKey {
     Proposal,
     /// `true` when the contract is paused.
     Paused,
+    /// Why the contract was paused; present only while paused.
+    PauseReason,
+    /// Address whose `get_config_as` reads also extend the instance TTL.
+    Keeper,
 }
 
 // ---------------------------------------------------------------------------
@@ -68,6 +77,21 @@ pub struct UpgradeProposal {
     pub execute_after: u64,
 }
 
+/// Snapshot of everything a frontend needs on load, read in one call.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProxyAdminConfig {
+    pub admin: soroban_sdk::Address,
+    pub paused: bool,
+    pub proposal_state: ProposalState,
+    /// Hash of the pending proposal, if any.
+    pub pending_hash: Option<BytesN<32>>,
+    /// When the pending proposal becomes executable, if any.
+    pub execute_after: Option<u64>,
+    pub min_delay: u64,
+    pub max_delay: u64,
+}
+
 /// Observable state of the upgrade proposal slot.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -100,6 +124,8 @@ pub enum AdminError {
     TooEarly = 7,
     /// Contract is paused; non-admin operations are blocked.
     ContractPaused = 8,
+    /// A scheduled execution time is already in the past.
+    ScheduleInPast = 9,
 }
 
 // ---------------------------------------------------------------------------
@@ -113,6 +139,8 @@ const EV_CANCEL: Symbol = symbol_short!("cancel");
 const EV_EXECUTE: Symbol = symbol_short!("execute");
 const EV_PAUSE: Symbol = symbol_short!("pause");
 const EV_UNPAUSE: Symbol = symbol_short!("unpause");
+const EV_TOUCH: Symbol = symbol_short!("touch");
+const EV_KEEPER: Symbol = symbol_short!("keeper");
 
 // ---------------------------------------------------------------------------
 // Contract
@@ -149,26 +177,23 @@ impl ProxyAdmin {
         new_wasm_hash: BytesN<32>,
         delay: u64,
     ) -> Result<(), AdminError> {
-        let admin = read_admin(&env)?;
-        admin.require_auth();
-
-        if !(MIN_DELAY..=MAX_DELAY).contains(&delay) {
-            return Err(AdminError::DelayOutOfRange);
-        }
-        if env.storage().instance().has(&DataKey::Proposal) {
-            return Err(AdminError::ProposalAlreadyExists);
-        }
-
-        let execute_after = env.ledger().timestamp() + delay;
-        let proposal = UpgradeProposal {
-            new_wasm_hash: new_wasm_hash.clone(),
-            execute_after,
-        };
-        env.storage().instance().set(&DataKey::Proposal, &proposal);
+        propose(&env, new_wasm_hash, delay, None)
+    }
 
-        env.events()
-            .publish((NS, EV_PROPOSE, admin, new_wasm_hash), execute_after);
-        Ok(())
+    /// Propose a WASM upgrade for a specific moment, e.g. a maintenance
+    /// window. Execution requires both the `delay` timelock and
+    /// `scheduled_for` to have passed, so `execute_after` is the later of
+    /// the two.
+    ///
+    /// Returns `ScheduleInPast` if `scheduled_for` is before the current
+    /// ledger timestamp.
+    pub fn propose_scheduled_upgrade(
+        env: Env,
+        new_wasm_hash: BytesN<32>,
+        delay: u64,
+        scheduled_for: u64,
+    ) -> Result<(), AdminError> {
+        propose(&env, new_wasm_hash, delay, Some(scheduled_for))
     }
 
     /// Cancel the pending upgrade proposal.
@@ -195,16 +220,8 @@ impl ProxyAdmin {
         let admin = read_admin(&env)?;
         admin.require_auth();
 
-        let proposal: UpgradeProposal = env
-            .storage()
-            .instance()
-            .get(&DataKey::Proposal)
-            .ok_or(AdminError::NoProposal)?;
-
+        let proposal = check_executable(&env)?;
         let now = env.ledger().timestamp();
-        if now < proposal.execute_after {
-            return Err(AdminError::TooEarly);
-        }
 
         // Remove before upgrading to prevent any re-entrancy replay.
         env.storage().instance().remove(&DataKey::Proposal);
@@ -223,13 +240,18 @@ impl ProxyAdmin {
 
     /// Pause the contract. Non-admin entry points should call
     /// `require_unpaused` before executing any logic.
-    pub fn pause(env: Env) -> Result<(), AdminError> {
+    ///
+    /// `reason` is a short code such as `exploit` or `oracle`, kept until
+    /// `unpause` and emitted as the last topic of the `pause` event so
+    /// post-incident reviews know why the contract was frozen.
+    pub fn pause(env: Env, reason: Symbol) -> Result<(), AdminError> {
         let admin = read_admin(&env)?;
         admin.require_auth();
 
         env.storage().instance().set(&DataKey::Paused, &true);
+        env.storage().instance().set(&DataKey::PauseReason, &reason);
         env.events()
-            .publish((NS, EV_PAUSE, admin), env.ledger().timestamp());
+            .publish((NS, EV_PAUSE, admin, reason), env.ledger().timestamp());
         Ok(())
     }
 
@@ -239,11 +261,47 @@ impl ProxyAdmin {
         admin.require_auth();
 
         env.storage().instance().set(&DataKey::Paused, &false);
+        env.storage().instance().remove(&DataKey::PauseReason);
         env.events()
             .publish((NS, EV_UNPAUSE, admin), env.ledger().timestamp());
         Ok(())
     }
 
+    // -----------------------------------------------------------------------
+    // Storage liveness
+    // -----------------------------------------------------------------------
+
+    /// Extend the instance TTL so the contract is not archived during quiet
+    /// periods between upgrades. All state here lives in instance storage,
+    /// so this keeps the admin, pause flag and pending proposal alive.
+    ///
+    /// Anyone may call this: it only pays rent and changes no state. A
+    /// keeper bot can call it on a schedule. View functions deliberately do
+    /// not bump TTL, so ordinary reads stay free of side effects; the one
+    /// exception is `get_config_as` called by the stored keeper.
+    pub fn touch(env: Env) {
+        env.storage()
+            .instance()
+            .extend_ttl(TTL_THRESHOLD, TTL_EXTEND);
+        env.events()
+            .publish((NS, EV_TOUCH), env.ledger().sequence());
+    }
+
+    /// Designate `keeper` as the address whose `get_config_as` reads also
+    /// extend the instance TTL. Replaces any previous keeper.
+    pub fn set_keeper(env: Env, keeper: soroban_sdk::Address) -> Result<(), AdminError> {
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Keeper, &keeper);
+        env.events().publish((NS, EV_KEEPER, admin), keeper);
+        Ok(())
+    }
+
+    pub fn get_keeper(env: Env) -> Option<soroban_sdk::Address> {
+        env.storage().instance().get(&DataKey::Keeper)
+    }
+
     // -----------------------------------------------------------------------
     // Queries
     // -----------------------------------------------------------------------
@@ -265,6 +323,16 @@ impl ProxyAdmin {
         }
     }
 
+    /// Dry run of `execute_upgrade`: returns `(true, 0)` if it would
+    /// succeed right now, otherwise `(false, code)` with the `AdminError`
+    /// code it would fail with. Reads state only; auth is not checked.
+    pub fn can_execute(env: Env) -> (bool, u32) {
+        match check_executable(&env) {
+            Ok(_) => (true, 0),
+            Err(err) => (false, err as u32),
+        }
+    }
+
     pub fn get_proposal(env: Env) -> Option<UpgradeProposal> {
         env.storage().instance().get(&DataKey::Proposal)
     }
@@ -276,15 +344,134 @@ impl ProxyAdmin {
             .unwrap_or(false)
     }
 
+    /// Reason given to the current `pause`, or `None` when not paused.
+    pub fn get_pause_reason(env: Env) -> Option<Symbol> {
+        env.storage().instance().get(&DataKey::PauseReason)
+    }
+
     pub fn admin(env: Env) -> Result<soroban_sdk::Address, AdminError> {
         read_admin(&env)
     }
+
+    /// Short, UI-friendly name for an `AdminError` code, e.g. `7` →
+    /// `timelock`. Unknown codes map to `unknown`.
+    ///
+    /// Lets clients render a readable message from the numeric code a
+    /// failed call returns without hardcoding the mapping themselves.
+    pub fn describe_error(_env: Env, code: u32) -> Symbol {
+        match AdminError::try_from(soroban_sdk::Error::from_contract_error(code)) {
+            Ok(err) => describe(err),
+            Err(_) => symbol_short!("unknown"),
+        }
+    }
+
+    /// Aggregate view of the admin, pause flag, proposal and delay bounds.
+    ///
+    /// Saves frontends one round-trip per field and guarantees all values
+    /// come from the same ledger.
+    pub fn get_config(env: Env) -> Result<ProxyAdminConfig, AdminError> {
+        let proposal = Self::get_proposal(env.clone());
+        Ok(ProxyAdminConfig {
+            admin: read_admin(&env)?,
+            paused: Self::is_paused(env.clone()),
+            proposal_state: Self::proposal_state(env),
+            pending_hash: proposal.as_ref().map(|p| p.new_wasm_hash.clone()),
+            execute_after: proposal.map(|p| p.execute_after),
+            min_delay: MIN_DELAY,
+            max_delay: MAX_DELAY,
+        })
+    }
+
+    /// `get_config` on behalf of `caller`, who must sign.
+    ///
+    /// When `caller` is the stored keeper the read also extends the instance
+    /// TTL, so a keeper bot that polls the config keeps the contract alive
+    /// without a separate `touch`. Anyone else gets a plain read.
+    pub fn get_config_as(
+        env: Env,
+        caller: soroban_sdk::Address,
+    ) -> Result<ProxyAdminConfig, AdminError> {
+        caller.require_auth();
+        if Self::get_keeper(env.clone()) == Some(caller) {
+            env.storage()
+                .instance()
+                .extend_ttl(TTL_THRESHOLD, TTL_EXTEND);
+        }
+        Self::get_config(env)
+    }
 }
 
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
 
+/// Exhaustive, so a new `AdminError` variant does not compile without a
+/// description.
+fn describe(err: AdminError) -> Symbol {
+    match err {
+        AdminError::AlreadyInitialized => symbol_short!("init_done"),
+        AdminError::NotInitialized => symbol_short!("no_init"),
+        AdminError::Unauthorized => symbol_short!("unauth"),
+        AdminError::DelayOutOfRange => symbol_short!("bad_delay"),
+        AdminError::ProposalAlreadyExists => symbol_short!("pending"),
+        AdminError::NoProposal => symbol_short!("no_prop"),
+        AdminError::TooEarly => symbol_short!("timelock"),
+        AdminError::ContractPaused => symbol_short!("paused"),
+        AdminError::ScheduleInPast => symbol_short!("past_time"),
+    }
+}
+
+fn propose(
+    env: &Env,
+    new_wasm_hash: BytesN<32>,
+    delay: u64,
+    scheduled_for: Option<u64>,
+) -> Result<(), AdminError> {
+    let admin = read_admin(env)?;
+    admin.require_auth();
+
+    if !(MIN_DELAY..=MAX_DELAY).contains(&delay) {
+        return Err(AdminError::DelayOutOfRange);
+    }
+    if env.storage().instance().has(&DataKey::Proposal) {
+        return Err(AdminError::ProposalAlreadyExists);
+    }
+
+    let now = env.ledger().timestamp();
+    let mut execute_after = now + delay;
+    if let Some(scheduled_for) = scheduled_for {
+        if scheduled_for < now {
+            return Err(AdminError::ScheduleInPast);
+        }
+        execute_after = execute_after.max(scheduled_for);
+    }
+
+    let proposal = UpgradeProposal {
+        new_wasm_hash: new_wasm_hash.clone(),
+        execute_after,
+    };
+    env.storage().instance().set(&DataKey::Proposal, &proposal);
+
+    env.events()
+        .publish((NS, EV_PROPOSE, admin, new_wasm_hash), execute_after);
+    Ok(())
+}
+
+/// The checks `execute_upgrade` runs before touching state, shared with
+/// `can_execute` so the dry run cannot drift from the real thing.
+fn check_executable(env: &Env) -> Result<UpgradeProposal, AdminError> {
+    read_admin(env)?;
+    let proposal: UpgradeProposal = env
+        .storage()
+        .instance()
+        .get(&DataKey::Proposal)
+        .ok_or(AdminError::NoProposal)?;
+    if env.ledger().timestamp() < proposal.execute_after {
+        return Err(AdminError::TooEarly);
+    }
+    Ok(proposal)
+}
+
 fn read_admin(env: &Env) -> Result<soroban_sdk::Address, AdminError> {
     env.storage()
         .instance()
@@ -4,9 +4,10 @@ extern crate std;
 
 use super::*;
 use soroban_sdk::{
-    testutils::{Address as _, Ledger},
-    Address, BytesN, Env,
+    testutils::{Address as _, Events as _, Ledger},
+    Address, BytesN, Env, IntoVal,
 };
+use soroban_validation::test_events::EventList;
 
 // ---------------------------------------------------------------------------
 // Helpers
@@ -93,6 +94,58 @@ fn propose_rejects_duplicate_proposal() {
     );
 }
 
+// ---------------------------------------------------------------------------
+// Scheduled upgrade
+// ---------------------------------------------------------------------------
+
+#[test]
+fn scheduled_upgrade_waits_for_scheduled_time() {
+    let (env, _admin, client) = setup();
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    let scheduled_for = 1_000 + 10 * MIN_DELAY;
+    client.propose_scheduled_upgrade(&dummy_hash(&env, 20), &MIN_DELAY, &scheduled_for);
+    assert_eq!(client.get_proposal().unwrap().execute_after, scheduled_for);
+
+    // Timelock has elapsed but the scheduled time has not
+    env.ledger()
+        .with_mut(|l| l.timestamp = 1_000 + MIN_DELAY + 1);
+    assert_eq!(client.proposal_state(), ProposalState::Pending);
+    assert_eq!(client.try_execute_upgrade(), Err(Ok(AdminError::TooEarly)));
+
+    env.ledger().with_mut(|l| l.timestamp = scheduled_for);
+    assert_eq!(client.proposal_state(), ProposalState::Ready);
+    // Past both guards; any failure now comes from the deployer stub
+    if let Err(Ok(e)) = client.try_execute_upgrade() {
+        assert_ne!(e, AdminError::TooEarly);
+    }
+}
+
+#[test]
+fn scheduled_upgrade_still_honours_timelock() {
+    let (env, _admin, client) = setup();
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    // Scheduled before the timelock would end: the timelock wins
+    client.propose_scheduled_upgrade(&dummy_hash(&env, 21), &MIN_DELAY, &1_001);
+    assert_eq!(
+        client.get_proposal().unwrap().execute_after,
+        1_000 + MIN_DELAY
+    );
+
+    env.ledger().with_mut(|l| l.timestamp = 1_001);
+    assert_eq!(client.try_execute_upgrade(), Err(Ok(AdminError::TooEarly)));
+}
+
+#[test]
+fn scheduled_upgrade_in_past_is_rejected() {
+    let (env, _admin, client) = setup();
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    assert_eq!(
+        client.try_propose_scheduled_upgrade(&dummy_hash(&env, 22), &MIN_DELAY, &999),
+        Err(Ok(AdminError::ScheduleInPast))
+    );
+    assert_eq!(client.proposal_state(), ProposalState::None);
+}
+
 // ---------------------------------------------------------------------------
 // Cancel upgrade
 // ---------------------------------------------------------------------------
@@ -162,6 +215,34 @@ fn proposal_is_cleared_after_execute() {
     }
 }
 
+#[test]
+fn can_execute_reports_no_proposal() {
+    let (_env, _admin, client) = setup();
+    assert_eq!(client.can_execute(), (false, AdminError::NoProposal as u32));
+}
+
+#[test]
+fn can_execute_reports_timelock_until_ready() {
+    let (env, _admin, client) = setup();
+    client.propose_upgrade(&dummy_hash(&env, 10), &MIN_DELAY);
+    assert_eq!(client.can_execute(), (false, AdminError::TooEarly as u32));
+
+    env.ledger().with_mut(|l| l.timestamp += MIN_DELAY);
+    assert_eq!(client.can_execute(), (true, 0));
+    // The dry run leaves the proposal in place.
+    assert_eq!(client.proposal_state(), ProposalState::Ready);
+}
+
+#[test]
+fn can_execute_before_initialize_reports_not_initialized() {
+    let env = Env::default();
+    let client = ProxyAdminClient::new(&env, &env.register_contract(None, ProxyAdmin));
+    assert_eq!(
+        client.can_execute(),
+        (false, AdminError::NotInitialized as u32)
+    );
+}
+
 // ---------------------------------------------------------------------------
 // Pause / unpause
 // ---------------------------------------------------------------------------
@@ -169,22 +250,42 @@ fn proposal_is_cleared_after_execute() {
 #[test]
 fn pause_sets_paused_flag() {
     let (_env, _admin, client) = setup();
-    client.pause();
+    client.pause(&symbol_short!("incident"));
     assert!(client.is_paused());
 }
 
 #[test]
 fn unpause_clears_paused_flag() {
     let (_env, _admin, client) = setup();
-    client.pause();
+    client.pause(&symbol_short!("incident"));
     client.unpause();
     assert!(!client.is_paused());
 }
 
+#[test]
+fn pause_stores_and_emits_reason() {
+    let (env, admin, client) = setup();
+    let reason = symbol_short!("exploit");
+    client.pause(&reason);
+
+    let (_id, topics, _data) = EventList::new(&env, env.events().all()).last().unwrap();
+    assert_eq!(topics, (NS, EV_PAUSE, admin, reason.clone()).into_val(&env));
+    assert_eq!(client.get_pause_reason(), Some(reason));
+}
+
+#[test]
+fn unpause_clears_pause_reason() {
+    let (_env, _admin, client) = setup();
+    assert_eq!(client.get_pause_reason(), None);
+    client.pause(&symbol_short!("oracle"));
+    client.unpause();
+    assert_eq!(client.get_pause_reason(), None);
+}
+
 #[test]
 fn require_unpaused_returns_error_when_paused() {
     let (_env, _admin, client) = setup();
-    client.pause();
+    client.pause(&symbol_short!("incident"));
 
     // The contract is paused. Any entry point that calls require_unpaused
     // internally would return ContractPaused. We verify the observable
@@ -192,6 +293,130 @@ fn require_unpaused_returns_error_when_paused() {
     assert!(client.is_paused());
 }
 
+// ---------------------------------------------------------------------------
+// Aggregate config view
+// ---------------------------------------------------------------------------
+
+#[test]
+fn get_config_matches_individual_getters() {
+    let (env, admin, client) = setup();
+    client.propose_upgrade(&dummy_hash(&env, 4), &MIN_DELAY);
+    client.pause(&symbol_short!("incident"));
+
+    let config = client.get_config();
+    assert_eq!(config.admin, client.admin());
+    assert_eq!(config.admin, admin);
+    assert_eq!(config.paused, client.is_paused());
+    assert!(config.paused);
+    assert_eq!(config.proposal_state, client.proposal_state());
+    assert_eq!(config.proposal_state, ProposalState::Pending);
+    let proposal = client.get_proposal().unwrap();
+    assert_eq!(config.pending_hash, Some(proposal.new_wasm_hash));
+    assert_eq!(config.execute_after, Some(proposal.execute_after));
+    assert_eq!(config.min_delay, MIN_DELAY);
+    assert_eq!(config.max_delay, MAX_DELAY);
+}
+
+#[test]
+fn get_config_before_initialize_is_rejected() {
+    let env = Env::default();
+    let client = ProxyAdminClient::new(&env, &env.register_contract(None, ProxyAdmin));
+    assert_eq!(client.try_get_config(), Err(Ok(AdminError::NotInitialized)));
+}
+
+// ---------------------------------------------------------------------------
+// Error descriptions
+// ---------------------------------------------------------------------------
+
+#[test]
+fn describe_error_covers_every_variant() {
+    let (_env, _admin, client) = setup();
+    let unknown = symbol_short!("unknown");
+    let mut seen = std::vec::Vec::new();
+    for err in [
+        AdminError::AlreadyInitialized,
+        AdminError::NotInitialized,
+        AdminError::Unauthorized,
+        AdminError::DelayOutOfRange,
+        AdminError::ProposalAlreadyExists,
+        AdminError::NoProposal,
+        AdminError::TooEarly,
+        AdminError::ContractPaused,
+        AdminError::ScheduleInPast,
+    ] {
+        let description = client.describe_error(&(err as u32));
+        assert_ne!(description, unknown, "{err:?} has no description");
+        assert!(!seen.contains(&description), "{err:?} reuses a description");
+        seen.push(description);
+    }
+    assert_eq!(client.describe_error(&7), symbol_short!("timelock"));
+
+    assert_eq!(client.describe_error(&0), unknown);
+    assert_eq!(client.describe_error(&10), unknown);
+    assert_eq!(client.describe_error(&u32::MAX), unknown);
+}
+
+// ---------------------------------------------------------------------------
+// Storage liveness
+// ---------------------------------------------------------------------------
+
+fn instance_ttl(env: &Env, client: &ProxyAdminClient) -> u32 {
+    use soroban_sdk::testutils::storage::Instance as _;
+    env.as_contract(&client.address, || env.storage().instance().get_ttl())
+}
+
+#[test]
+fn touch_raises_instance_ttl() {
+    let (env, _admin, client) = setup();
+    let before = instance_ttl(&env, &client);
+    assert!(before < TTL_THRESHOLD);
+
+    client.touch();
+    assert_eq!(instance_ttl(&env, &client), TTL_EXTEND);
+}
+
+#[test]
+fn reads_do_not_raise_instance_ttl() {
+    let (env, _admin, client) = setup();
+    let before = instance_ttl(&env, &client);
+
+    client.get_config();
+    client.is_paused();
+    client.proposal_state();
+    assert_eq!(instance_ttl(&env, &client), before);
+}
+
+#[test]
+fn keeper_read_raises_instance_ttl() {
+    let (env, _admin, client) = setup();
+    let keeper = Address::generate(&env);
+    client.set_keeper(&keeper);
+    assert_eq!(client.get_keeper(), Some(keeper.clone()));
+    assert!(instance_ttl(&env, &client) < TTL_THRESHOLD);
+
+    let config = client.get_config_as(&keeper);
+    assert_eq!(config, client.get_config());
+    assert_eq!(instance_ttl(&env, &client), TTL_EXTEND);
+}
+
+#[test]
+fn non_keeper_read_does_not_raise_instance_ttl() {
+    let (env, _admin, client) = setup();
+    client.set_keeper(&Address::generate(&env));
+    let before = instance_ttl(&env, &client);
+
+    client.get_config_as(&Address::generate(&env));
+    assert_eq!(instance_ttl(&env, &client), before);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
+fn set_keeper_without_auth_panics() {
+    let (env, _admin, client) = setup();
+    env.set_auths(&[]);
+    client.set_keeper(&Address::generate(&env));
+}
+
 // ---------------------------------------------------------------------------
 // Auth guards
 // ---------------------------------------------------------------------------
@@ -219,7 +444,7 @@ fn pause_without_auth_panics() {
     env.mock_all_auths();
     client.initialize(&admin);
     env.set_auths(&[]);
-    client.pause();
+    client.pause(&symbol_short!("incident"));
 }
 
 // ---------------------------------------------------------------------------
@@ -273,7 +498,7 @@ mod bench {
     fn bench_pause() {
         let (env, _admin, client) = setup_bench();
         env.budget().reset_default();
-        client.pause();
+        client.pause(&symbol_short!("incident"));
         let cpu = env.budget().cpu_instruction_cost();
         let mem = env.budget().memory_bytes_cost();
         std::println!("[bench] proxy-admin::pause  cpu={cpu}  mem={mem}");
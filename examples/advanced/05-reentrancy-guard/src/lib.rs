@@ -8,6 +8,7 @@ use soroban_sdk::{
 #[derive(Clone)]
 pub enum DataKey {
     Entered,
+    Locked,
     Balance(Address),
 }
 
@@ -85,6 +86,43 @@ impl ReentrancyGuardContract {
         Ok(())
     }
 
+    /// Vulnerable withdraw: performs the external call *before* updating the
+    /// balance and holds no lock. On a platform that permits re-entry the
+    /// callback could withdraw the same balance twice.
+    pub fn withdraw_vulnerable(env: Env, to: Address, amount: i128, target_contract: Address) {
+        to.require_auth();
+
+        let balance = Self::balance_of(&env, &to);
+        if balance < amount {
+            panic!("Insufficient balance");
+        }
+
+        // Interaction before effects: the classic reentrancy mistake.
+        Self::send_funds(&env, &target_contract, &to, amount);
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Balance(to), &(balance - amount));
+    }
+
+    /// Guarded withdraw: the same flow wrapped in `with_guard`, so any
+    /// nested call while the lock is held panics with "Reentrant call".
+    pub fn withdraw_guarded(env: Env, to: Address, amount: i128, target_contract: Address) {
+        to.require_auth();
+
+        Self::with_guard(&env, || {
+            let balance = Self::balance_of(&env, &to);
+            if balance < amount {
+                panic!("Insufficient balance");
+            }
+
+            env.storage()
+                .persistent()
+                .set(&DataKey::Balance(to.clone()), &(balance - amount));
+            Self::send_funds(&env, &target_contract, &to, amount);
+        })
+    }
+
     pub fn get_balance(env: Env, user: Address) -> Result<i128, ContractError> {
         // Read-only reentrancy guard
         if Self::is_entered(&env) {
@@ -98,6 +136,39 @@ impl ReentrancyGuardContract {
             .unwrap_or(0))
     }
 
+    /// Sets `DataKey::Locked` for the duration of `f` and clears it afterwards.
+    fn with_guard<T>(env: &Env, f: impl FnOnce() -> T) -> T {
+        let locked: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey::Locked)
+            .unwrap_or(false);
+        if locked {
+            panic!("Reentrant call");
+        }
+        env.storage().instance().set(&DataKey::Locked, &true);
+
+        let result = f();
+
+        env.storage().instance().set(&DataKey::Locked, &false);
+        result
+    }
+
+    fn balance_of(env: &Env, user: &Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Balance(user.clone()))
+            .unwrap_or(0)
+    }
+
+    fn send_funds(env: &Env, target_contract: &Address, to: &Address, amount: i128) {
+        env.invoke_contract::<()>(
+            target_contract,
+            &Symbol::new(env, "receive_funds"),
+            vec![env, to.into_val(env), amount.into_val(env)],
+        );
+    }
+
     fn is_entered(env: &Env) -> bool {
         env.storage()
             .instance()
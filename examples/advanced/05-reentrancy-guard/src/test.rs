@@ -2,7 +2,11 @@
 
 use super::*;
 use soroban_sdk::{
-    contract, contractimpl, testutils::Address as _, vec, Address, Env, IntoVal, Symbol,
+    contract, contractimpl,
+    testutils::Address as _,
+    vec,
+    xdr::{ScErrorCode, ScErrorType},
+    Address, Env, IntoVal, Symbol,
 };
 
 #[contracttype]
@@ -49,6 +53,23 @@ impl MaliciousContract {
                     env.current_contract_address().into_val(&env),
                 ],
             );
+        } else if attack_type == 3 || attack_type == 4 {
+            // Attempt to re-enter the vulnerable (3) or guarded (4) withdraw
+            let func = if attack_type == 3 {
+                "withdraw_vulnerable"
+            } else {
+                "withdraw_guarded"
+            };
+            let _: () = env.invoke_contract(
+                &main_contract,
+                &Symbol::new(&env, func),
+                vec![
+                    &env,
+                    to.into_val(&env),
+                    amount.into_val(&env),
+                    env.current_contract_address().into_val(&env),
+                ],
+            );
         } else if attack_type == 2 {
             // Attempt to read-only re-enter
             let _: i128 = env.invoke_contract(
@@ -124,3 +145,103 @@ fn test_readonly_reentrancy_prevented() {
 
     client.withdraw(&user, &500, &malicious_target);
 }
+
+fn setup_with_target(
+    env: &Env,
+    attack_type: u32,
+) -> (ReentrancyGuardContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+
+    let contract_id = env.register(ReentrancyGuardContract, ());
+    let client = ReentrancyGuardContractClient::new(env, &contract_id);
+    client.init();
+
+    let user = Address::generate(env);
+    client.deposit(&user, &1000);
+
+    let target = env.register(MaliciousContract, ());
+    MaliciousContractClient::new(env, &target).init(&contract_id, &attack_type);
+
+    (client, user, target)
+}
+
+#[test]
+fn test_guarded_withdraw_releases_lock() {
+    let env = Env::default();
+    let (client, user, safe_target) = setup_with_target(&env, 0);
+
+    // Two sequential calls succeed, proving the lock is cleared after each one
+    client.withdraw_guarded(&user, &300, &safe_target);
+    client.withdraw_guarded(&user, &300, &safe_target);
+    assert_eq!(client.get_balance(&user), 400);
+}
+
+#[test]
+fn test_guarded_withdraw_reentry_rejected_by_host() {
+    let env = Env::default();
+    let (client, user, malicious_target) = setup_with_target(&env, 4);
+
+    // The callback's re-entry never reaches the guard: the host refuses to
+    // let a contract re-enter itself through `invoke_contract`, so this
+    // shows host behaviour, not the lock.
+    let result = client.try_withdraw_guarded(&user, &500, &malicious_target);
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_type_and_code(
+            ScErrorType::Context,
+            ScErrorCode::InvalidAction
+        )))
+    );
+    // The whole invocation rolls back, so no funds left the contract
+    assert_eq!(client.get_balance(&user), 1000);
+}
+
+#[test]
+#[should_panic(expected = "Reentrant call")]
+fn test_guard_rejects_nested_guarded_call() {
+    let env = Env::default();
+    let (client, user, safe_target) = setup_with_target(&env, 0);
+
+    // Cross-contract self re-entry is stopped by the host (see above), so
+    // nest the call inside the contract's own frame instead: the entry point
+    // runs again while an outer guarded section still holds the lock, and
+    // the contract's own check must reject it.
+    env.as_contract(&client.address, || {
+        ReentrancyGuardContract::with_guard(&env, || {
+            ReentrancyGuardContract::withdraw_guarded(
+                env.clone(),
+                user.clone(),
+                100,
+                safe_target.clone(),
+            )
+        })
+    });
+}
+
+#[test]
+#[should_panic(expected = "Reentrant call")]
+fn test_guard_rejects_call_while_locked() {
+    let env = Env::default();
+    let (client, user, safe_target) = setup_with_target(&env, 0);
+
+    // Simulate being mid-call: the lock is held when withdraw_guarded is entered
+    env.as_contract(&client.address, || {
+        env.storage().instance().set(&DataKey::Locked, &true);
+    });
+
+    client.withdraw_guarded(&user, &500, &safe_target);
+}
+
+#[test]
+fn test_vulnerable_withdraw_reentry_rejected_by_host() {
+    let env = Env::default();
+    let (client, user, malicious_target) = setup_with_target(&env, 3);
+
+    // withdraw_vulnerable updates the balance only after the callback, so
+    // re-entering it would see the original balance and withdraw it twice.
+    // The Soroban host refuses to let a contract re-enter itself, so the
+    // callback fails and the whole call rolls back instead of double-spending.
+    let result = client.try_withdraw_vulnerable(&user, &500, &malicious_target);
+    assert!(result.is_err());
+    assert_eq!(client.get_balance(&user), 1000);
+}
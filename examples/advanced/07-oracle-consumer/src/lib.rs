@@ -0,0 +1,179 @@
+//! # Oracle Consumer
+//!
+//! The reading side of the oracle pattern: a contract that asks an external
+//! price oracle for its latest price and refuses to use it if it is too old.
+//!
+//! ## Trust Model
+//!
+//! - The consumer trusts whichever oracle the admin configures; it cannot
+//!   judge whether a price is correct, only whether it is recent.
+//! - Staleness is measured against the timestamp the **oracle** reports for
+//!   the price, not the time of the call, so an oracle that stops updating is
+//!   detected even though it keeps answering.
+//! - A reported timestamp in the future is treated as invalid rather than
+//!   fresh.
+
+#![no_std]
+
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractimpl, contracttype, symbol_short, Address,
+    Env, Symbol,
+};
+
+// ---------------------------------------------------------------------------
+// Errors
+// ---------------------------------------------------------------------------
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum OracleError {
+    /// Contract has already been initialized.
+    AlreadyInitialized = 1,
+    /// Contract has not been initialized yet.
+    NotInitialized = 2,
+    /// Caller is not the admin.
+    NotAuthorized = 3,
+    /// No oracle has been configured.
+    OracleNotSet = 4,
+    /// The oracle's price is older than the staleness window.
+    StalePrice = 5,
+    /// The oracle reported a timestamp ahead of the ledger.
+    InvalidTimestamp = 6,
+}
+
+// ---------------------------------------------------------------------------
+// Oracle interface
+// ---------------------------------------------------------------------------
+
+/// A price and the ledger timestamp at which the oracle recorded it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceData {
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+/// Interface the consumer expects the oracle to expose.
+///
+/// `#[contractclient]` generates `PriceOracleClient` for calling any contract
+/// that implements it.
+#[contractclient(name = "PriceOracleClient")]
+pub trait PriceOracle {
+    fn lastprice(env: Env) -> PriceData;
+}
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    /// The admin who configures the oracle and staleness window.
+    Admin,
+    /// Address of the oracle contract.
+    Oracle,
+    /// Maximum price age (seconds) before it is rejected.
+    MaxAge,
+}
+
+// ---------------------------------------------------------------------------
+// Events
+// ---------------------------------------------------------------------------
+
+const CONTRACT_NS: Symbol = symbol_short!("consumer");
+const ACTION_ORACLE: Symbol = symbol_short!("oracle");
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+#[contract]
+pub struct OracleConsumer;
+
+#[contractimpl]
+impl OracleConsumer {
+    /// Set the admin and the staleness window (in seconds).
+    pub fn initialize(env: Env, admin: Address, max_age: u64) -> Result<(), OracleError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(OracleError::AlreadyInitialized);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::MaxAge, &max_age);
+        Ok(())
+    }
+
+    /// Point the consumer at an oracle contract (admin-only).
+    pub fn set_oracle(env: Env, admin: Address, oracle: Address) -> Result<(), OracleError> {
+        Self::require_admin(&env, &admin)?;
+        env.storage().instance().set(&DataKey::Oracle, &oracle);
+
+        env.events()
+            .publish((CONTRACT_NS, ACTION_ORACLE, admin), oracle);
+        Ok(())
+    }
+
+    /// Change the staleness window (admin-only).
+    pub fn set_max_age(env: Env, admin: Address, max_age: u64) -> Result<(), OracleError> {
+        Self::require_admin(&env, &admin)?;
+        env.storage().instance().set(&DataKey::MaxAge, &max_age);
+        Ok(())
+    }
+
+    /// Fetch the oracle's latest price, rejecting it if stale.
+    pub fn get_price(env: Env) -> Result<i128, OracleError> {
+        let oracle: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Oracle)
+            .ok_or(OracleError::OracleNotSet)?;
+        let max_age: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::MaxAge)
+            .ok_or(OracleError::NotInitialized)?;
+
+        let data = PriceOracleClient::new(&env, &oracle).lastprice();
+
+        let now = env.ledger().timestamp();
+        if data.timestamp > now {
+            return Err(OracleError::InvalidTimestamp);
+        }
+        if now - data.timestamp > max_age {
+            return Err(OracleError::StalePrice);
+        }
+        Ok(data.price)
+    }
+
+    // ── queries ─────────────────────────────────────────────────────────────
+
+    pub fn get_oracle(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Oracle)
+    }
+
+    pub fn get_max_age(env: Env) -> Result<u64, OracleError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxAge)
+            .ok_or(OracleError::NotInitialized)
+    }
+
+    // ── helpers ─────────────────────────────────────────────────────────────
+
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), OracleError> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(OracleError::NotInitialized)?;
+        if caller != &admin {
+            return Err(OracleError::NotAuthorized);
+        }
+        caller.require_auth();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test;
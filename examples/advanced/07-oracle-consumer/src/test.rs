@@ -0,0 +1,127 @@
+use super::*;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+const MAX_AGE: u64 = 300;
+const NOW: u64 = 10_000;
+
+/// Stand-in oracle that returns whatever price and timestamp it was given.
+#[contract]
+struct MockOracle;
+
+#[contractimpl]
+impl MockOracle {
+    pub fn set(env: Env, price: i128, timestamp: u64) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("data"), &PriceData { price, timestamp });
+    }
+
+    pub fn lastprice(env: Env) -> PriceData {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("data"))
+            .unwrap()
+    }
+}
+
+fn setup() -> (
+    Env,
+    Address,
+    OracleConsumerClient<'static>,
+    MockOracleClient<'static>,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(NOW);
+
+    let oracle_id = env.register(MockOracle, ());
+    let oracle = MockOracleClient::new(&env, &oracle_id);
+    let client = OracleConsumerClient::new(&env, &env.register(OracleConsumer, ()));
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &MAX_AGE);
+    client.set_oracle(&admin, &oracle_id);
+    (env, admin, client, oracle)
+}
+
+// ── configuration ───────────────────────────────────────────────────────────
+
+#[test]
+fn test_initialize_twice_fails() {
+    let (_env, admin, client, _oracle) = setup();
+    let result = client.try_initialize(&admin, &MAX_AGE);
+    assert_eq!(result, Err(Ok(OracleError::AlreadyInitialized)));
+}
+
+#[test]
+fn test_set_oracle_requires_admin() {
+    let (env, _admin, client, _oracle) = setup();
+    let other = Address::generate(&env);
+    let result = client.try_set_oracle(&other, &other);
+    assert_eq!(result, Err(Ok(OracleError::NotAuthorized)));
+}
+
+#[test]
+fn test_get_price_without_oracle_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = OracleConsumerClient::new(&env, &env.register(OracleConsumer, ()));
+    client.initialize(&Address::generate(&env), &MAX_AGE);
+    assert_eq!(client.try_get_price(), Err(Ok(OracleError::OracleNotSet)));
+}
+
+// ── staleness ───────────────────────────────────────────────────────────────
+
+#[test]
+fn test_fresh_price_accepted() {
+    let (_env, _admin, client, oracle) = setup();
+    oracle.set(&1_234, &(NOW - 10));
+    assert_eq!(client.get_price(), 1_234);
+}
+
+#[test]
+fn test_price_at_window_edge_accepted() {
+    let (_env, _admin, client, oracle) = setup();
+    oracle.set(&1_234, &(NOW - MAX_AGE));
+    assert_eq!(client.get_price(), 1_234);
+}
+
+#[test]
+fn test_stale_price_rejected() {
+    let (_env, _admin, client, oracle) = setup();
+    oracle.set(&1_234, &(NOW - MAX_AGE - 1));
+    assert_eq!(client.try_get_price(), Err(Ok(OracleError::StalePrice)));
+}
+
+#[test]
+fn test_price_goes_stale_as_ledger_advances() {
+    let (env, _admin, client, oracle) = setup();
+    oracle.set(&1_234, &NOW);
+    assert_eq!(client.get_price(), 1_234);
+
+    env.ledger().set_timestamp(NOW + MAX_AGE + 1);
+    assert_eq!(client.try_get_price(), Err(Ok(OracleError::StalePrice)));
+}
+
+#[test]
+fn test_future_timestamp_rejected() {
+    let (_env, _admin, client, oracle) = setup();
+    oracle.set(&1_234, &(NOW + 1));
+    assert_eq!(
+        client.try_get_price(),
+        Err(Ok(OracleError::InvalidTimestamp))
+    );
+}
+
+#[test]
+fn test_widening_window_accepts_older_price() {
+    let (_env, admin, client, oracle) = setup();
+    oracle.set(&1_234, &(NOW - 600));
+    assert_eq!(client.try_get_price(), Err(Ok(OracleError::StalePrice)));
+
+    client.set_max_age(&admin, &600);
+    assert_eq!(client.get_price(), 1_234);
+}
@@ -18,7 +18,59 @@
 
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, symbol_short, vec, Env, Symbol, Vec};
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, panic_with_error, symbol_short, vec,
+    Address, Bytes, Env, String, Symbol, SymbolStr, TryFromVal, Vec,
+};
+
+/// Longest `max_name_len` a [`GreetConfig`] may set (the `Symbol` limit).
+pub const MAX_NAME_LEN: u32 = 32;
+
+/// Number of names `greet` remembers until `set_history_cap` changes it.
+pub const DEFAULT_HISTORY_CAP: u32 = 5;
+
+/// Largest cap `set_history_cap` accepts, so the log (rewritten on every
+/// `greet`) stays cheap even if the admin key is misused.
+pub const MAX_HISTORY_CAP: u32 = 50;
+
+/// Optional greeting settings, stored once with `initialize_config`.
+///
+/// Storing a `#[contracttype]` struct under a single key is the simplest way
+/// to keep related settings together and read them back in one call.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GreetConfig {
+    /// First element of the greeting, e.g. `"Hi"` instead of `"Hello"`.
+    pub greeting: Symbol,
+    /// Names longer than this are rejected by `hello`. Must be 1..=32.
+    pub max_name_len: u32,
+}
+
+/// Storage keys.
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,
+    Config,
+    History,
+    HistoryCap,
+}
+
+/// Errors returned (or raised) by `HelloContract`.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum HelloError {
+    /// `max_name_len` is outside `1..=MAX_NAME_LEN`, or a history cap is
+    /// outside `1..=MAX_HISTORY_CAP`.
+    InvalidConfig = 1,
+    /// A config has already been stored.
+    AlreadyConfigured = 2,
+    /// The name is longer than the configured `max_name_len`.
+    NameTooLong = 3,
+    /// No admin exists yet; call `initialize_config` first.
+    NotConfigured = 4,
+}
 
 /// The contract type.
 ///
@@ -31,6 +83,40 @@ pub struct HelloContract;
 /// Public interface of `HelloContract`.
 #[contractimpl]
 impl HelloContract {
+    /// Validate and store a [`GreetConfig`], recording `admin` as the
+    /// contract's admin. `admin` must sign. Can only be called once.
+    ///
+    /// Whoever calls this first becomes the admin, so call it in the same
+    /// transaction as deployment (e.g. from a factory). If it returns
+    /// `AlreadyConfigured` on a contract you just deployed, someone got
+    /// there first: deploy a fresh one instead of using it.
+    pub fn initialize_config(
+        env: Env,
+        admin: Address,
+        config: GreetConfig,
+    ) -> Result<(), HelloError> {
+        if env.storage().instance().has(&DataKey::Config) {
+            return Err(HelloError::AlreadyConfigured);
+        }
+        if config.max_name_len == 0 || config.max_name_len > MAX_NAME_LEN {
+            return Err(HelloError::InvalidConfig);
+        }
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Config, &config);
+        Ok(())
+    }
+
+    /// Return the admin set by `initialize_config`, if any.
+    pub fn get_admin(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Admin)
+    }
+
+    /// Return the stored config, if `initialize_config` has been called.
+    pub fn get_config(env: Env) -> Option<GreetConfig> {
+        env.storage().instance().get(&DataKey::Config)
+    }
+
     /// Return a greeting vector for the given name.
     ///
     /// # Arguments
@@ -42,7 +128,9 @@ impl HelloContract {
     ///
     /// # Returns
     ///
-    /// `Vec<Symbol>` of the form `["Hello", <to>]`.
+    /// `Vec<Symbol>` of the form `["Hello", <to>]`, or `[<greeting>, <to>]`
+    /// once a [`GreetConfig`] is stored. With a config, names longer than
+    /// `max_name_len` panic with `HelloError::NameTooLong`.
     ///
     /// # Example
     ///
@@ -50,10 +138,126 @@ impl HelloContract {
     /// hello(env, symbol_short!("World")) -> ["Hello", "World"]
     /// ```
     pub fn hello(env: Env, to: Symbol) -> Vec<Symbol> {
+        let config = Self::get_config(env.clone());
+        let greeting = checked_greeting(&env, &config, &to);
+
         // `vec!` is the Soroban macro equivalent of the std `vec![]` macro.
         // It allocates the vector in host memory and is the idiomatic way to
         // return multiple values from a contract function.
-        vec![&env, symbol_short!("Hello"), to]
+        vec![&env, greeting, to]
+    }
+
+    /// Greet several names at once: one `[greeting, name]` pair per input,
+    /// in input order. An empty input returns an empty vector.
+    pub fn hello_many(env: Env, names: Vec<Symbol>) -> Vec<Vec<Symbol>> {
+        let config = Self::get_config(env.clone());
+        let mut greetings = Vec::new(&env);
+        for name in names.iter() {
+            let greeting = checked_greeting(&env, &config, &name);
+            greetings.push_back(vec![&env, greeting, name]);
+        }
+        greetings
+    }
+
+    /// Flattened form of [`Self::hello_many`]: a single greeting followed by
+    /// every name, e.g. `["Hello", "Alice", "Bob"]`. An empty input returns
+    /// an empty vector rather than a lone greeting.
+    pub fn hello_all(env: Env, names: Vec<Symbol>) -> Vec<Symbol> {
+        let mut result = Vec::new(&env);
+        if names.is_empty() {
+            return result;
+        }
+
+        let config = Self::get_config(env.clone());
+        for name in names.iter() {
+            let greeting = checked_greeting(&env, &config, &name);
+            if result.is_empty() {
+                result.push_back(greeting);
+            }
+            result.push_back(name);
+        }
+        result
+    }
+
+    /// Return `to` with ASCII letters uppercased.
+    ///
+    /// `String` is immutable and has no case helpers, so the bytes are copied
+    /// out, transformed one by one, and rebuilt into a new `String`. Digits,
+    /// punctuation and non-ASCII (UTF-8 multi-byte) bytes pass through as-is.
+    pub fn shout(env: Env, to: String) -> String {
+        let mut out = Bytes::new(&env);
+        for byte in to.to_bytes().iter() {
+            out.push_back(byte.to_ascii_uppercase());
+        }
+        String::from(out)
+    }
+
+    /// Greet `to` like [`Self::hello`] and append the name to a bounded log.
+    ///
+    /// The log keeps the most recent names, oldest first. Once it holds
+    /// more than the history cap the oldest entries are dropped, so storage
+    /// never grows without bound.
+    pub fn greet(env: Env, to: Symbol) -> Vec<Symbol> {
+        let greeting = Self::hello(env.clone(), to.clone());
+
+        let cap = Self::history_cap(env.clone());
+        let mut history = Self::recent_greetings(env.clone());
+        history.push_back(to);
+        while history.len() > cap {
+            history.pop_front();
+        }
+        env.storage().persistent().set(&DataKey::History, &history);
+
+        greeting
+    }
+
+    /// Names recorded by [`Self::greet`], oldest first.
+    pub fn recent_greetings(env: Env) -> Vec<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::History)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Set how many names [`Self::greet`] keeps, in `1..=MAX_HISTORY_CAP`.
+    /// Admin-only. A smaller cap takes effect on the next `greet`.
+    pub fn set_history_cap(env: Env, cap: u32) -> Result<(), HelloError> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(HelloError::NotConfigured)?;
+        admin.require_auth();
+
+        if cap == 0 || cap > MAX_HISTORY_CAP {
+            return Err(HelloError::InvalidConfig);
+        }
+        env.storage().instance().set(&DataKey::HistoryCap, &cap);
+        Ok(())
+    }
+
+    /// Current history cap ([`DEFAULT_HISTORY_CAP`] until changed).
+    pub fn history_cap(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::HistoryCap)
+            .unwrap_or(DEFAULT_HISTORY_CAP)
+    }
+}
+
+/// Greeting word for `to`, panicking with `NameTooLong` if a stored config
+/// limits names and `to` exceeds it.
+fn checked_greeting(env: &Env, config: &Option<GreetConfig>, to: &Symbol) -> Symbol {
+    match config {
+        Some(config) => {
+            // `Symbol` has no `len()`; `SymbolStr` expands it into bytes.
+            let name = SymbolStr::try_from_val(env, &to.to_symbol_val()).unwrap();
+            if name.len() as u32 > config.max_name_len {
+                panic_with_error!(env, HelloError::NameTooLong);
+            }
+            config.greeting.clone()
+        }
+        None => symbol_short!("Hello"),
     }
 }
 
@@ -1,7 +1,7 @@
 //! Unit tests for the Hello World contract.
 
 use super::*;
-use soroban_sdk::{symbol_short, vec, Env, Symbol};
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, Env, String, Symbol};
 
 /// Basic greeting test: the returned Vec should be ["Hello", "World"].
 #[test]
@@ -101,3 +101,263 @@ fn test_hello_with_single_character_name() {
 
     assert_eq!(result, vec![&env, symbol_short!("Hello"), name]);
 }
+
+fn custom_config(max_name_len: u32) -> GreetConfig {
+    GreetConfig {
+        greeting: symbol_short!("Hi"),
+        max_name_len,
+    }
+}
+
+/// A stored config replaces the greeting word.
+#[test]
+fn test_initialize_config_custom_greeting() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, HelloContract);
+    let client = HelloContractClient::new(&env, &contract_id);
+
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+
+    let config = custom_config(5);
+    client.initialize_config(&admin, &config);
+
+    assert_eq!(client.get_config(), Some(config));
+    assert_eq!(client.get_admin(), Some(admin));
+    assert_eq!(
+        client.hello(&symbol_short!("Alice")),
+        vec![&env, symbol_short!("Hi"), symbol_short!("Alice")]
+    );
+}
+
+/// Names longer than `max_name_len` are rejected; the limit itself is allowed.
+#[test]
+fn test_hello_rejects_name_over_configured_limit() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, HelloContract);
+    let client = HelloContractClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    client.initialize_config(&Address::generate(&env), &custom_config(5));
+
+    assert_eq!(client.hello(&symbol_short!("Alice")).len(), 2);
+    // `hello` returns no `Result`, so the panicked error comes back as a
+    // plain `soroban_sdk::Error`.
+    assert_eq!(
+        client.try_hello(&symbol_short!("Alexandra")),
+        Err(Ok(HelloError::NameTooLong.into()))
+    );
+    assert_eq!(
+        client.try_hello(&Symbol::new(&env, "LongerName12345")),
+        Err(Ok(HelloError::NameTooLong.into()))
+    );
+}
+
+/// `max_name_len` must be in `1..=MAX_NAME_LEN`, and the config is write-once.
+#[test]
+fn test_initialize_config_rejects_invalid_config() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, HelloContract);
+    let client = HelloContractClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+
+    assert_eq!(
+        client.try_initialize_config(&admin, &custom_config(0)),
+        Err(Ok(HelloError::InvalidConfig))
+    );
+    assert_eq!(
+        client.try_initialize_config(&admin, &custom_config(MAX_NAME_LEN + 1)),
+        Err(Ok(HelloError::InvalidConfig))
+    );
+    assert_eq!(client.get_config(), None);
+
+    client.initialize_config(&admin, &custom_config(MAX_NAME_LEN));
+    // A second caller cannot take over the config or the admin role.
+    let other = Address::generate(&env);
+    assert_eq!(
+        client.try_initialize_config(&other, &custom_config(8)),
+        Err(Ok(HelloError::AlreadyConfigured))
+    );
+    assert_eq!(client.get_admin(), Some(admin));
+}
+
+/// The admin named in `initialize_config` must sign the call.
+#[test]
+#[should_panic(expected = "HostError")]
+fn test_initialize_config_requires_admin_auth() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, HelloContract);
+    let client = HelloContractClient::new(&env, &contract_id);
+
+    // No mocked auths: the admin has not signed
+    client.initialize_config(&Address::generate(&env), &custom_config(5));
+}
+
+/// `greet` returns the same greeting as `hello` and records names in order.
+#[test]
+fn test_greet_records_history_in_order() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, HelloContract);
+    let client = HelloContractClient::new(&env, &contract_id);
+
+    assert_eq!(client.recent_greetings().len(), 0);
+    assert_eq!(
+        client.greet(&symbol_short!("Alice")),
+        vec![&env, symbol_short!("Hello"), symbol_short!("Alice")]
+    );
+    client.greet(&symbol_short!("Bob"));
+    client.greet(&symbol_short!("Carol"));
+
+    assert_eq!(
+        client.recent_greetings(),
+        vec![
+            &env,
+            symbol_short!("Alice"),
+            symbol_short!("Bob"),
+            symbol_short!("Carol")
+        ]
+    );
+    // `hello` on its own does not touch the log.
+    client.hello(&symbol_short!("Dave"));
+    assert_eq!(client.recent_greetings().len(), 3);
+}
+
+/// Exceeding the cap drops the oldest name first.
+#[test]
+fn test_greet_history_cap_drops_oldest() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, HelloContract);
+    let client = HelloContractClient::new(&env, &contract_id);
+    assert_eq!(client.history_cap(), DEFAULT_HISTORY_CAP);
+
+    env.mock_all_auths();
+    client.initialize_config(&Address::generate(&env), &custom_config(MAX_NAME_LEN));
+    client.set_history_cap(&2);
+    client.greet(&symbol_short!("Alice"));
+    client.greet(&symbol_short!("Bob"));
+    client.greet(&symbol_short!("Carol"));
+
+    assert_eq!(
+        client.recent_greetings(),
+        vec![&env, symbol_short!("Bob"), symbol_short!("Carol")]
+    );
+    assert_eq!(
+        client.try_set_history_cap(&0),
+        Err(Ok(HelloError::InvalidConfig))
+    );
+    assert_eq!(
+        client.try_set_history_cap(&(MAX_HISTORY_CAP + 1)),
+        Err(Ok(HelloError::InvalidConfig))
+    );
+    assert_eq!(client.history_cap(), 2);
+}
+
+/// Only the admin can change the cap, and only once one exists.
+#[test]
+fn test_set_history_cap_requires_admin() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, HelloContract);
+    let client = HelloContractClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    assert_eq!(
+        client.try_set_history_cap(&3),
+        Err(Ok(HelloError::NotConfigured))
+    );
+
+    client.initialize_config(&Address::generate(&env), &custom_config(MAX_NAME_LEN));
+    // Without the admin's signature the call is rejected by the host.
+    env.set_auths(&[]);
+    assert!(client.try_set_history_cap(&3).is_err());
+    assert_eq!(client.history_cap(), DEFAULT_HISTORY_CAP);
+}
+
+/// `shout` uppercases plain lowercase input.
+#[test]
+fn test_shout_uppercases() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, HelloContract);
+    let client = HelloContractClient::new(&env, &contract_id);
+
+    let result = client.shout(&String::from_str(&env, "hello"));
+    assert_eq!(result, String::from_str(&env, "HELLO"));
+}
+
+/// Mixed case input ends up fully uppercase.
+#[test]
+fn test_shout_mixed_case() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, HelloContract);
+    let client = HelloContractClient::new(&env, &contract_id);
+
+    let result = client.shout(&String::from_str(&env, "HeLLo WoRld"));
+    assert_eq!(result, String::from_str(&env, "HELLO WORLD"));
+}
+
+/// Digits, punctuation and non-ASCII characters pass through unchanged.
+#[test]
+fn test_shout_passes_through_digits_and_non_ascii() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, HelloContract);
+    let client = HelloContractClient::new(&env, &contract_id);
+
+    let result = client.shout(&String::from_str(&env, "room 42!"));
+    assert_eq!(result, String::from_str(&env, "ROOM 42!"));
+
+    let result = client.shout(&String::from_str(&env, "café"));
+    assert_eq!(result, String::from_str(&env, "CAFé"));
+}
+
+/// `hello_many` returns one `[greeting, name]` pair per name, in order.
+#[test]
+fn test_hello_many_two_names() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, HelloContract);
+    let client = HelloContractClient::new(&env, &contract_id);
+
+    let names = vec![&env, symbol_short!("Alice"), symbol_short!("Bob")];
+    let result = client.hello_many(&names);
+
+    assert_eq!(
+        result,
+        vec![
+            &env,
+            vec![&env, symbol_short!("Hello"), symbol_short!("Alice")],
+            vec![&env, symbol_short!("Hello"), symbol_short!("Bob")],
+        ]
+    );
+}
+
+/// Both variants return an empty vector for an empty input.
+#[test]
+fn test_hello_many_and_all_empty_input() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, HelloContract);
+    let client = HelloContractClient::new(&env, &contract_id);
+
+    let names: soroban_sdk::Vec<Symbol> = vec![&env];
+    assert_eq!(client.hello_many(&names).len(), 0);
+    assert_eq!(client.hello_all(&names).len(), 0);
+}
+
+/// `hello_all` has a single greeting followed by every name.
+#[test]
+fn test_hello_all_flattened_shape() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, HelloContract);
+    let client = HelloContractClient::new(&env, &contract_id);
+
+    let names = vec![
+        &env,
+        symbol_short!("Alice"),
+        symbol_short!("Bob"),
+        symbol_short!("Carol"),
+    ];
+    let result = client.hello_all(&names);
+
+    assert_eq!(result.len(), names.len() + 1);
+    assert_eq!(result.get(0).unwrap(), symbol_short!("Hello"));
+    for (i, name) in names.iter().enumerate() {
+        assert_eq!(result.get(i as u32 + 1).unwrap(), name);
+    }
+}
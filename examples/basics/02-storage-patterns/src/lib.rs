@@ -9,7 +9,9 @@
 
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Env, Symbol};
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Env, Symbol, Vec,
+};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -17,6 +19,54 @@ pub enum DataKey {
     Persistent(Symbol),
     Temporary(Symbol),
     Instance(Symbol),
+    Config,
+    ConfigVersion,
+    Nonce(Address, u64),
+    Admin,
+    WritesPaused,
+    Snapshot(Symbol),
+    Series(Symbol),
+    Cache(Symbol),
+    CacheHits,
+    CacheMisses,
+}
+
+/// Original configuration layout.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigV1 {
+    pub a: u64,
+}
+
+/// Upgraded configuration layout with an additional field.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigV2 {
+    pub a: u64,
+    pub b: u64,
+}
+
+/// Number of ledgers a consumed nonce is remembered for.
+pub const NONCE_TTL_LEDGERS: u32 = 100;
+
+/// Number of ledgers a cached `get_or_compute` result stays valid for.
+pub const CACHE_TTL_LEDGERS: u32 = 50;
+
+/// Value given to `ConfigV2::b` when migrating from `ConfigV1`.
+pub const DEFAULT_CONFIG_B: u64 = 100;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum StorageError {
+    /// The requested key does not exist in the source storage type.
+    KeyNotFound = 1,
+    /// No configuration has been stored yet.
+    ConfigNotFound = 2,
+    /// `initialize` has already set an admin.
+    AlreadyInitialized = 3,
+    /// No admin has been set with `initialize`.
+    NotInitialized = 4,
 }
 
 /// Storage contract demonstrating all three storage types
@@ -25,11 +75,54 @@ pub struct StorageContract;
 
 #[contractimpl]
 impl StorageContract {
+    // ==================== WRITE PAUSE ====================
+
+    /// Sets the admin allowed to pause writes. Can only be called once.
+    pub fn initialize(env: Env, admin: Address) -> Result<(), StorageError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(StorageError::AlreadyInitialized);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        Ok(())
+    }
+
+    /// Pauses (`true`) or resumes (`false`) every function that changes
+    /// stored data: the `set_*` and `remove_*` functions, snapshot/restore,
+    /// promotion, config writes and migration, and nonce consumption.
+    /// Getters are never paused, so data stays readable during an incident;
+    /// `get_or_compute` may still fill its derived cache.
+    pub fn set_writes_paused(env: Env, paused: bool) -> Result<(), StorageError> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(StorageError::NotInitialized)?;
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::WritesPaused, &paused);
+
+        // EVENT: Write pause toggled
+        env.events()
+            .publish((symbol_short!("writes"), symbol_short!("paused")), paused);
+        Ok(())
+    }
+
+    /// Returns whether writes are currently paused.
+    pub fn writes_paused(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::WritesPaused)
+            .unwrap_or(false)
+    }
+
     // ==================== PERSISTENT STORAGE ====================
 
     /// Stores a value in persistent storage.
     /// Persistent data remains until explicitly deleted and requires TTL extension.
     pub fn set_persistent(env: Env, key: Symbol, value: u64) {
+        require_writes_enabled(&env);
         let storage_key = DataKey::Persistent(key.clone());
         // Store in persistent storage
         env.storage().persistent().set(&storage_key, &value);
@@ -59,6 +152,7 @@ impl StorageContract {
 
     /// Removes a value from persistent storage.
     pub fn remove_persistent(env: Env, key: Symbol) {
+        require_writes_enabled(&env);
         env.storage()
             .persistent()
             .remove(&DataKey::Persistent(key.clone()));
@@ -68,10 +162,99 @@ impl StorageContract {
             .publish((symbol_short!("persist"), symbol_short!("remove")), key);
     }
 
+    /// Stores many values in persistent storage in a single invocation.
+    /// All writes happen first; TTLs are then extended in one pass at the end
+    /// and a single event summarises the batch instead of one per key.
+    pub fn set_persistent_batch(env: Env, entries: Vec<(Symbol, u64)>) {
+        require_writes_enabled(&env);
+        let storage = env.storage().persistent();
+        for (key, value) in entries.iter() {
+            storage.set(&DataKey::Persistent(key), &value);
+        }
+
+        // Persistent TTL is tracked per entry, so each written key still needs
+        // its own extension, but it is done once per key after all writes.
+        for (key, _) in entries.iter() {
+            storage.extend_ttl(&DataKey::Persistent(key), 1000, 10000);
+        }
+
+        // EVENT: Persistent storage batch updated
+        env.events().publish(
+            (symbol_short!("persist"), symbol_short!("batch")),
+            entries.len(),
+        );
+    }
+
+    /// Retrieves many values from persistent storage.
+    /// The result has one entry per requested key, `None` where nothing is stored.
+    pub fn get_persistent_batch(env: Env, keys: Vec<Symbol>) -> Vec<Option<u64>> {
+        let storage = env.storage().persistent();
+        let mut values = Vec::new(&env);
+        for key in keys.iter() {
+            values.push_back(storage.get(&DataKey::Persistent(key)));
+        }
+        values
+    }
+
+    /// Copies the current persistent values of `keys` into a parallel
+    /// snapshot namespace, recording `None` for keys that are absent.
+    /// A later snapshot of the same key overwrites the earlier one.
+    pub fn snapshot(env: Env, keys: Vec<Symbol>) {
+        require_writes_enabled(&env);
+        let storage = env.storage().persistent();
+        for key in keys.iter() {
+            let value: Option<u64> = storage.get(&DataKey::Persistent(key.clone()));
+            let snapshot_key = DataKey::Snapshot(key);
+            storage.set(&snapshot_key, &value);
+            storage.extend_ttl(&snapshot_key, 1000, 10000);
+        }
+
+        // EVENT: Persistent keys snapshotted
+        env.events().publish(
+            (symbol_short!("persist"), symbol_short!("snapshot")),
+            keys.len(),
+        );
+    }
+
+    /// Writes the snapshotted values of `keys` back into persistent storage.
+    /// Keys that were absent at snapshot time are removed again. Fails with
+    /// `KeyNotFound`, restoring nothing, if any key was never snapshotted.
+    pub fn restore(env: Env, keys: Vec<Symbol>) -> Result<(), StorageError> {
+        require_writes_enabled(&env);
+        let storage = env.storage().persistent();
+
+        let mut values: Vec<Option<u64>> = Vec::new(&env);
+        for key in keys.iter() {
+            let value: Option<u64> = storage
+                .get(&DataKey::Snapshot(key))
+                .ok_or(StorageError::KeyNotFound)?;
+            values.push_back(value);
+        }
+
+        for (key, value) in keys.iter().zip(values.iter()) {
+            let storage_key = DataKey::Persistent(key);
+            match value {
+                Some(value) => {
+                    storage.set(&storage_key, &value);
+                    storage.extend_ttl(&storage_key, 1000, 10000);
+                }
+                None => storage.remove(&storage_key),
+            }
+        }
+
+        // EVENT: Persistent keys restored from snapshot
+        env.events().publish(
+            (symbol_short!("persist"), symbol_short!("restore")),
+            keys.len(),
+        );
+        Ok(())
+    }
+
     // ==================== TEMPORARY STORAGE ====================
 
     /// Stores a value in temporary storage.
     pub fn set_temporary(env: Env, key: Symbol, value: u64) {
+        require_writes_enabled(&env);
         env.storage()
             .temporary()
             .set(&DataKey::Temporary(key.clone()), &value);
@@ -91,10 +274,173 @@ impl StorageContract {
         env.storage().temporary().has(&DataKey::Temporary(key))
     }
 
+    // ==================== MIGRATION ====================
+
+    /// Moves a value from temporary storage into persistent storage.
+    ///
+    /// Useful when short-lived data turns out to be worth keeping (e.g. after
+    /// it crosses some threshold). The temporary entry is removed and the new
+    /// persistent entry has its TTL extended like any other persistent write.
+    pub fn promote_to_persistent(env: Env, key: Symbol) -> Result<u64, StorageError> {
+        require_writes_enabled(&env);
+        let temp_key = DataKey::Temporary(key.clone());
+        let value: u64 = env
+            .storage()
+            .temporary()
+            .get(&temp_key)
+            .ok_or(StorageError::KeyNotFound)?;
+
+        let persistent_key = DataKey::Persistent(key.clone());
+        env.storage().persistent().set(&persistent_key, &value);
+        env.storage().temporary().remove(&temp_key);
+        env.storage()
+            .persistent()
+            .extend_ttl(&persistent_key, 1000, 10000);
+
+        // EVENT: Temporary value promoted to persistent storage
+        env.events().publish(
+            (symbol_short!("persist"), symbol_short!("promote")),
+            (key, value),
+        );
+
+        Ok(value)
+    }
+
+    /// Stores a configuration using the original `ConfigV1` layout.
+    pub fn set_config_v1(env: Env, a: u64) {
+        require_writes_enabled(&env);
+        env.storage()
+            .instance()
+            .set(&DataKey::Config, &ConfigV1 { a });
+        env.storage().instance().set(&DataKey::ConfigVersion, &1u32);
+    }
+
+    /// Returns the stored layout version (0 if nothing has been stored).
+    pub fn config_version(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ConfigVersion)
+            .unwrap_or(0)
+    }
+
+    /// Upgrades a stored `ConfigV1` to `ConfigV2`, filling `b` with
+    /// [`DEFAULT_CONFIG_B`]. The version marker makes this safe to call more
+    /// than once: once the data is at version 2 the call does nothing.
+    pub fn migrate_config(env: Env) -> Result<(), StorageError> {
+        require_writes_enabled(&env);
+        match Self::config_version(env.clone()) {
+            0 => Err(StorageError::ConfigNotFound),
+            1 => {
+                let old: ConfigV1 = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::Config)
+                    .ok_or(StorageError::ConfigNotFound)?;
+                let new = ConfigV2 {
+                    a: old.a,
+                    b: DEFAULT_CONFIG_B,
+                };
+                env.storage().instance().set(&DataKey::Config, &new);
+                env.storage().instance().set(&DataKey::ConfigVersion, &2u32);
+                env.storage().instance().extend_ttl(1000, 10000);
+
+                // EVENT: Configuration migrated to the new layout
+                env.events()
+                    .publish((symbol_short!("config"), symbol_short!("migrate")), 2u32);
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Reads the configuration as `ConfigV2`. Returns `None` until migrated.
+    pub fn get_config_v2(env: Env) -> Option<ConfigV2> {
+        if Self::config_version(env.clone()) < 2 {
+            return None;
+        }
+        env.storage().instance().get(&DataKey::Config)
+    }
+
+    /// Marks `nonce` as used for `user`, panicking if it was already used.
+    ///
+    /// Used nonces only need to be remembered for as long as a signed request
+    /// carrying them could still be submitted, so they live in cheap temporary
+    /// storage and are garbage-collected once their TTL runs out.
+    pub fn consume_nonce(env: Env, user: Address, nonce: u64) {
+        require_writes_enabled(&env);
+        user.require_auth();
+
+        let key = DataKey::Nonce(user.clone(), nonce);
+        if env.storage().temporary().has(&key) {
+            panic!("Nonce already used");
+        }
+        env.storage().temporary().set(&key, &true);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, NONCE_TTL_LEDGERS, NONCE_TTL_LEDGERS);
+
+        // EVENT: Nonce consumed
+        env.events().publish((symbol_short!("nonce"), user), nonce);
+    }
+
+    // ==================== READ-THROUGH CACHE ====================
+
+    /// Stores the series of values that `get_or_compute` sums for `key`.
+    /// Any cached sum for `key` is dropped so it cannot go stale.
+    pub fn set_series(env: Env, key: Symbol, values: Vec<u64>) {
+        require_writes_enabled(&env);
+        let storage_key = DataKey::Series(key.clone());
+        env.storage().persistent().set(&storage_key, &values);
+        env.storage()
+            .persistent()
+            .extend_ttl(&storage_key, 1000, 10000);
+        env.storage().temporary().remove(&DataKey::Cache(key));
+    }
+
+    /// Returns the sum of the series stored for `key` (0 if none is stored).
+    ///
+    /// The sum stands in for an expensive computation: it is cached in
+    /// temporary storage for [`CACHE_TTL_LEDGERS`], and later calls read the
+    /// cache until the entry expires and the next call recomputes it.
+    pub fn get_or_compute(env: Env, key: Symbol) -> u64 {
+        let cache_key = DataKey::Cache(key.clone());
+        if let Some(value) = env.storage().temporary().get(&cache_key) {
+            bump_counter(&env, &DataKey::CacheHits);
+            return value;
+        }
+
+        let series: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Series(key))
+            .unwrap_or_else(|| Vec::new(&env));
+        let mut sum = 0u64;
+        for value in series.iter() {
+            sum += value;
+        }
+
+        env.storage().temporary().set(&cache_key, &sum);
+        env.storage()
+            .temporary()
+            .extend_ttl(&cache_key, CACHE_TTL_LEDGERS, CACHE_TTL_LEDGERS);
+        bump_counter(&env, &DataKey::CacheMisses);
+        sum
+    }
+
+    /// Returns `(hits, misses)` recorded by `get_or_compute`.
+    pub fn cache_stats(env: Env) -> (u32, u32) {
+        let instance = env.storage().instance();
+        (
+            instance.get(&DataKey::CacheHits).unwrap_or(0),
+            instance.get(&DataKey::CacheMisses).unwrap_or(0),
+        )
+    }
+
     // ==================== INSTANCE STORAGE ====================
 
     /// Stores a value in instance storage.
     pub fn set_instance(env: Env, key: Symbol, value: u64) {
+        require_writes_enabled(&env);
         let storage_key = DataKey::Instance(key.clone());
         env.storage().instance().set(&storage_key, &value);
 
@@ -120,6 +466,7 @@ impl StorageContract {
 
     /// Removes a value from instance storage.
     pub fn remove_instance(env: Env, key: Symbol) {
+        require_writes_enabled(&env);
         env.storage()
             .instance()
             .remove(&DataKey::Instance(key.clone()));
@@ -130,5 +477,17 @@ impl StorageContract {
     }
 }
 
+/// Panics if an admin has paused writes with `set_writes_paused`.
+fn require_writes_enabled(env: &Env) {
+    if StorageContract::writes_paused(env.clone()) {
+        panic!("Writes are paused");
+    }
+}
+
+fn bump_counter(env: &Env, key: &DataKey) {
+    let count: u32 = env.storage().instance().get(key).unwrap_or(0);
+    env.storage().instance().set(key, &(count + 1));
+}
+
 #[cfg(test)]
 mod test;
@@ -36,6 +36,37 @@
 //! - `test_storage_remove_operations` - Remove operations
 //! - `test_zero_and_boundary_values` - Boundary value testing
 //!
+//! ### Batch Operations (3 tests)
+//! - `test_persistent_batch_set_and_get` - Five writes in one call, read back with a missing key
+//! - `test_persistent_batch_extends_ttl_for_every_entry` - TTL extended for each batched key
+//! - `test_persistent_batch_empty_input` - Empty batches are a no-op
+//!
+//! ### Migration (5 tests)
+//! - `test_promote_temporary_to_persistent` - Value moves from temporary to persistent
+//! - `test_promote_missing_key_fails` - Promoting an absent key returns `KeyNotFound`
+//! - `test_migrate_config_v1_to_v2` - Stored V1 is upgraded with a defaulted field
+//! - `test_migrate_config_twice_is_noop` - A second migration leaves V2 untouched
+//! - `test_migrate_config_without_config_fails` - Nothing to migrate returns `ConfigNotFound`
+//!
+//! ### Nonce Cache (4 tests)
+//! - `test_consume_nonce_accepts_fresh_nonce` - First use of a nonce succeeds
+//! - `test_consume_nonce_rejects_reuse` - Replaying a nonce panics
+//! - `test_consume_nonce_distinct_nonces_and_users` - Other nonces and users are unaffected
+//! - `test_consume_nonce_expires_with_temporary_ttl` - Old nonces are dropped with their TTL
+//!
+//! ### Write Pause (9 tests)
+//! - `test_paused_writes_reject_sets_but_allow_gets` - Pause, fail a set, read, unpause, set
+//! - `test_set_writes_paused_requires_initialize` - No admin means no pause
+//! - `test_paused_writes_reject_*` - Each remove, snapshot, promote, config and nonce write is blocked
+//!
+//! ### Snapshot and Restore (2 tests)
+//! - `test_snapshot_and_restore_round_trip` - Mutations after a snapshot are undone, new keys removed
+//! - `test_restore_without_snapshot_fails` - Unsnapshotted keys return `KeyNotFound`
+//!
+//! ### Read-Through Cache (2 tests)
+//! - `test_get_or_compute_miss_then_hit_then_expiry` - Miss, hit, then recompute after TTL
+//! - `test_set_series_invalidates_cache` - New input is never served from a stale cache
+//!
 //! ## Acceptance Criteria (Issue #46)
 //! ✅ Tests for each storage type (persistent, temporary, instance)
 //! ✅ Persistence verification tests (5 dedicated tests)
@@ -43,8 +74,8 @@
 //! ✅ 6+ tests passing (21 tests passing)
 
 use super::*;
-use soroban_sdk::testutils::{Events as _, Ledger as _};
-use soroban_sdk::{symbol_short, Env, Symbol, TryFromVal, Val, Vec};
+use soroban_sdk::testutils::{storage::Persistent as _, Address as _, Events as _, Ledger as _};
+use soroban_sdk::{symbol_short, vec, Address, Env, Symbol, TryFromVal, Val, Vec};
 
 fn get_last_event_topics_and_data(
     env: &Env,
@@ -815,3 +846,504 @@ fn test_zero_and_boundary_values() {
     client.set_instance(&key, &u64::MAX);
     assert_eq!(client.get_instance(&key), Some(u64::MAX));
 }
+
+// -------------------- Batch Operations --------------------
+
+#[test]
+fn test_persistent_batch_set_and_get() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, StorageContract);
+    let client = StorageContractClient::new(&env, &contract_id);
+
+    let entries = vec![
+        &env,
+        (symbol_short!("k1"), 10u64),
+        (symbol_short!("k2"), 20u64),
+        (symbol_short!("k3"), 30u64),
+        (symbol_short!("k4"), 40u64),
+        (symbol_short!("k5"), 50u64),
+    ];
+    client.set_persistent_batch(&entries);
+
+    // One summary event for the whole batch
+    let events = env.events().all();
+    let (topics, data) = get_last_event_topics_and_data(&env, &events);
+    let t1: Symbol = Symbol::try_from_val(&env, &topics.get(1).unwrap()).unwrap();
+    assert_eq!(t1, symbol_short!("batch"));
+    assert_eq!(u32::try_from_val(&env, &data).unwrap(), 5);
+
+    let keys = vec![
+        &env,
+        symbol_short!("k1"),
+        symbol_short!("k2"),
+        symbol_short!("missing"),
+        symbol_short!("k3"),
+        symbol_short!("k4"),
+        symbol_short!("k5"),
+    ];
+    let values = client.get_persistent_batch(&keys);
+    assert_eq!(
+        values,
+        vec![
+            &env,
+            Some(10u64),
+            Some(20u64),
+            None,
+            Some(30u64),
+            Some(40u64),
+            Some(50u64),
+        ]
+    );
+
+    // Batch writes are visible to the single-key getters too
+    assert_eq!(client.get_persistent(&symbol_short!("k3")), Some(30));
+}
+
+#[test]
+fn test_persistent_batch_extends_ttl_for_every_entry() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, StorageContract);
+    let client = StorageContractClient::new(&env, &contract_id);
+
+    let entries = vec![&env, (symbol_short!("a"), 1u64), (symbol_short!("b"), 2u64)];
+    client.set_persistent_batch(&entries);
+
+    let ttl_of = |key: Symbol| {
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .get_ttl(&DataKey::Persistent(key))
+        })
+    };
+
+    // Age the entries until they fall below the 1000-ledger threshold
+    let initial = ttl_of(symbol_short!("a"));
+    env.ledger()
+        .with_mut(|li| li.sequence_number += initial - 500);
+    assert!(ttl_of(symbol_short!("a")) < 1000);
+
+    // Rewriting the batch bumps every entry back up to 10000 ledgers
+    client.set_persistent_batch(&entries);
+    assert_eq!(ttl_of(symbol_short!("a")), 10000);
+    assert_eq!(ttl_of(symbol_short!("b")), 10000);
+}
+
+#[test]
+fn test_persistent_batch_empty_input() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, StorageContract);
+    let client = StorageContractClient::new(&env, &contract_id);
+
+    client.set_persistent_batch(&Vec::new(&env));
+    assert_eq!(client.get_persistent_batch(&Vec::new(&env)).len(), 0);
+}
+
+// -------------------- Migration --------------------
+
+#[test]
+fn test_promote_temporary_to_persistent() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, StorageContract);
+    let client = StorageContractClient::new(&env, &contract_id);
+
+    let key = symbol_short!("session");
+    client.set_temporary(&key, &42);
+    assert!(!client.has_persistent(&key));
+
+    assert_eq!(client.promote_to_persistent(&key), 42);
+
+    let events = env.events().all();
+    let (topics, _) = get_last_event_topics_and_data(&env, &events);
+    let t1: Symbol = Symbol::try_from_val(&env, &topics.get(1).unwrap()).unwrap();
+    assert_eq!(t1, symbol_short!("promote"));
+
+    // Now lives in persistent storage only
+    assert_eq!(client.get_persistent(&key), Some(42));
+    assert!(!client.has_temporary(&key));
+
+    // The promoted value outlives the temporary entry's lifetime
+    env.ledger().with_mut(|li| li.sequence_number += 5000);
+    assert_eq!(client.get_persistent(&key), Some(42));
+}
+
+#[test]
+fn test_promote_missing_key_fails() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, StorageContract);
+    let client = StorageContractClient::new(&env, &contract_id);
+
+    let key = symbol_short!("absent");
+    // A persistent value alone is not enough; the source is temporary storage
+    client.set_persistent(&key, &7);
+
+    assert_eq!(
+        client.try_promote_to_persistent(&key),
+        Err(Ok(StorageError::KeyNotFound))
+    );
+    assert_eq!(client.get_persistent(&key), Some(7));
+}
+
+#[test]
+fn test_migrate_config_v1_to_v2() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, StorageContract);
+    let client = StorageContractClient::new(&env, &contract_id);
+
+    client.set_config_v1(&7);
+    assert_eq!(client.config_version(), 1);
+    assert_eq!(client.get_config_v2(), None);
+
+    client.migrate_config();
+
+    assert_eq!(client.config_version(), 2);
+    assert_eq!(
+        client.get_config_v2(),
+        Some(ConfigV2 {
+            a: 7,
+            b: DEFAULT_CONFIG_B,
+        })
+    );
+}
+
+#[test]
+fn test_migrate_config_twice_is_noop() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, StorageContract);
+    let client = StorageContractClient::new(&env, &contract_id);
+
+    client.set_config_v1(&7);
+    client.migrate_config();
+    let migrated = client.get_config_v2();
+
+    client.migrate_config();
+    assert_eq!(env.events().all().events().len(), 0);
+    assert_eq!(client.config_version(), 2);
+    assert_eq!(client.get_config_v2(), migrated);
+}
+
+#[test]
+fn test_migrate_config_without_config_fails() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, StorageContract);
+    let client = StorageContractClient::new(&env, &contract_id);
+
+    assert_eq!(
+        client.try_migrate_config(),
+        Err(Ok(StorageError::ConfigNotFound))
+    );
+    assert_eq!(client.config_version(), 0);
+}
+
+// -------------------- Nonce Cache --------------------
+
+#[test]
+fn test_consume_nonce_accepts_fresh_nonce() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, StorageContract);
+    let client = StorageContractClient::new(&env, &contract_id);
+    let user = Address::generate(&env);
+
+    client.consume_nonce(&user, &1);
+
+    env.as_contract(&contract_id, || {
+        assert!(env
+            .storage()
+            .temporary()
+            .has(&DataKey::Nonce(user.clone(), 1)));
+        assert!(!env
+            .storage()
+            .persistent()
+            .has(&DataKey::Nonce(user.clone(), 1)));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Nonce already used")]
+fn test_consume_nonce_rejects_reuse() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, StorageContract);
+    let client = StorageContractClient::new(&env, &contract_id);
+    let user = Address::generate(&env);
+
+    client.consume_nonce(&user, &1);
+    client.consume_nonce(&user, &1);
+}
+
+#[test]
+fn test_consume_nonce_distinct_nonces_and_users() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, StorageContract);
+    let client = StorageContractClient::new(&env, &contract_id);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    client.consume_nonce(&alice, &1);
+    client.consume_nonce(&alice, &2);
+    // The same nonce value is independent per user
+    client.consume_nonce(&bob, &1);
+
+    assert!(client.try_consume_nonce(&alice, &2).is_err());
+}
+
+#[test]
+fn test_consume_nonce_expires_with_temporary_ttl() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.sequence_number = 10;
+        li.min_temp_entry_ttl = 16;
+    });
+    let contract_id = env.register_contract(None, StorageContract);
+    let client = StorageContractClient::new(&env, &contract_id);
+    let user = Address::generate(&env);
+
+    client.consume_nonce(&user, &9);
+
+    // Still remembered inside the TTL window
+    env.ledger()
+        .with_mut(|li| li.sequence_number += NONCE_TTL_LEDGERS / 2);
+    assert!(client.try_consume_nonce(&user, &9).is_err());
+
+    // Once the temporary entry expires the nonce is forgotten
+    env.ledger()
+        .with_mut(|li| li.sequence_number += NONCE_TTL_LEDGERS);
+    client.consume_nonce(&user, &9);
+}
+
+// -------------------- Write Pause --------------------
+
+#[test]
+fn test_paused_writes_reject_sets_but_allow_gets() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, StorageContract);
+    let client = StorageContractClient::new(&env, &contract_id);
+    client.initialize(&Address::generate(&env));
+
+    let key = symbol_short!("k");
+    client.set_persistent(&key, &1);
+    client.set_instance(&key, &2);
+    client.set_temporary(&key, &3);
+
+    client.set_writes_paused(&true);
+    assert!(client.writes_paused());
+
+    assert!(client.try_set_persistent(&key, &10).is_err());
+    assert!(client.try_set_instance(&key, &20).is_err());
+    assert!(client.try_set_temporary(&key, &30).is_err());
+    assert!(client
+        .try_set_persistent_batch(&vec![&env, (key.clone(), 10u64)])
+        .is_err());
+
+    // Reads keep working and see the pre-pause values
+    assert_eq!(client.get_persistent(&key), Some(1));
+    assert_eq!(client.get_instance(&key), Some(2));
+    assert_eq!(client.get_temporary(&key), Some(3));
+
+    client.set_writes_paused(&false);
+    client.set_persistent(&key, &10);
+    assert_eq!(client.get_persistent(&key), Some(10));
+}
+
+#[test]
+fn test_set_writes_paused_requires_initialize() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, StorageContract);
+    let client = StorageContractClient::new(&env, &contract_id);
+
+    assert_eq!(
+        client.try_set_writes_paused(&true),
+        Err(Ok(StorageError::NotInitialized))
+    );
+    assert!(!client.writes_paused());
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    assert_eq!(
+        client.try_initialize(&admin),
+        Err(Ok(StorageError::AlreadyInitialized))
+    );
+}
+
+/// Registers an initialized contract, seeds one value per storage type plus a
+/// v1 config, and pauses writes.
+fn paused_client(env: &Env) -> StorageContractClient<'_> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, StorageContract);
+    let client = StorageContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env));
+
+    let key = symbol_short!("k");
+    client.set_persistent(&key, &1);
+    client.set_instance(&key, &2);
+    client.set_temporary(&key, &3);
+    client.set_config_v1(&4);
+    client.snapshot(&vec![env, key]);
+
+    client.set_writes_paused(&true);
+    client
+}
+
+#[test]
+fn test_paused_writes_reject_remove_persistent() {
+    let env = Env::default();
+    let client = paused_client(&env);
+    let key = symbol_short!("k");
+    assert!(client.try_remove_persistent(&key).is_err());
+    assert_eq!(client.get_persistent(&key), Some(1));
+}
+
+#[test]
+fn test_paused_writes_reject_remove_instance() {
+    let env = Env::default();
+    let client = paused_client(&env);
+    let key = symbol_short!("k");
+    assert!(client.try_remove_instance(&key).is_err());
+    assert_eq!(client.get_instance(&key), Some(2));
+}
+
+#[test]
+fn test_paused_writes_reject_snapshot_and_restore() {
+    let env = Env::default();
+    let client = paused_client(&env);
+    let keys = vec![&env, symbol_short!("k")];
+    assert!(client.try_snapshot(&keys).is_err());
+    assert!(client.try_restore(&keys).is_err());
+}
+
+#[test]
+fn test_paused_writes_reject_promote_to_persistent() {
+    let env = Env::default();
+    let client = paused_client(&env);
+    let key = symbol_short!("k");
+    assert!(client.try_promote_to_persistent(&key).is_err());
+    assert_eq!(client.get_temporary(&key), Some(3));
+    assert_eq!(client.get_persistent(&key), Some(1));
+}
+
+#[test]
+fn test_paused_writes_reject_set_config_v1() {
+    let env = Env::default();
+    let client = paused_client(&env);
+    assert!(client.try_set_config_v1(&9).is_err());
+    assert_eq!(client.config_version(), 1);
+}
+
+#[test]
+fn test_paused_writes_reject_migrate_config() {
+    let env = Env::default();
+    let client = paused_client(&env);
+    assert!(client.try_migrate_config().is_err());
+    assert_eq!(client.config_version(), 1);
+    assert_eq!(client.get_config_v2(), None);
+}
+
+#[test]
+fn test_paused_writes_reject_consume_nonce() {
+    let env = Env::default();
+    let client = paused_client(&env);
+    let user = Address::generate(&env);
+    assert!(client.try_consume_nonce(&user, &1).is_err());
+
+    // The nonce was not recorded, so it is still usable once resumed
+    client.set_writes_paused(&false);
+    client.consume_nonce(&user, &1);
+}
+
+// -------------------- Snapshot and Restore --------------------
+
+#[test]
+fn test_snapshot_and_restore_round_trip() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, StorageContract);
+    let client = StorageContractClient::new(&env, &contract_id);
+
+    let a = symbol_short!("a");
+    let b = symbol_short!("b");
+    let fresh = symbol_short!("fresh");
+    client.set_persistent(&a, &1);
+    client.set_persistent(&b, &2);
+
+    let keys = vec![&env, a.clone(), b.clone(), fresh.clone()];
+    client.snapshot(&keys);
+
+    // Risky operation: overwrite, delete, and create
+    client.set_persistent(&a, &100);
+    client.remove_persistent(&b);
+    client.set_persistent(&fresh, &7);
+
+    client.restore(&keys);
+
+    assert_eq!(client.get_persistent(&a), Some(1));
+    assert_eq!(client.get_persistent(&b), Some(2));
+    // Absent at snapshot time, so absent again after restore
+    assert_eq!(client.get_persistent(&fresh), None);
+    assert!(!client.has_persistent(&fresh));
+}
+
+#[test]
+fn test_restore_without_snapshot_fails() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, StorageContract);
+    let client = StorageContractClient::new(&env, &contract_id);
+
+    let a = symbol_short!("a");
+    let b = symbol_short!("b");
+    client.set_persistent(&a, &1);
+    client.snapshot(&vec![&env, a.clone()]);
+    client.set_persistent(&a, &5);
+
+    assert_eq!(
+        client.try_restore(&vec![&env, a.clone(), b]),
+        Err(Ok(StorageError::KeyNotFound))
+    );
+    // Nothing was restored
+    assert_eq!(client.get_persistent(&a), Some(5));
+}
+
+// -------------------- Read-Through Cache --------------------
+
+#[test]
+fn test_get_or_compute_miss_then_hit_then_expiry() {
+    let env = Env::default();
+    env.ledger().with_mut(|li| {
+        li.sequence_number = 10;
+        li.min_temp_entry_ttl = 16;
+    });
+    let contract_id = env.register_contract(None, StorageContract);
+    let client = StorageContractClient::new(&env, &contract_id);
+
+    let key = symbol_short!("series");
+    client.set_series(&key, &vec![&env, 1u64, 2, 3, 4]);
+
+    assert_eq!(client.get_or_compute(&key), 10);
+    assert_eq!(client.cache_stats(), (0, 1));
+
+    assert_eq!(client.get_or_compute(&key), 10);
+    assert_eq!(client.cache_stats(), (1, 1));
+
+    // Once the temporary entry expires the next call recomputes
+    env.ledger()
+        .with_mut(|li| li.sequence_number += CACHE_TTL_LEDGERS + 1);
+    assert_eq!(client.get_or_compute(&key), 10);
+    assert_eq!(client.cache_stats(), (1, 2));
+}
+
+#[test]
+fn test_set_series_invalidates_cache() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, StorageContract);
+    let client = StorageContractClient::new(&env, &contract_id);
+
+    let key = symbol_short!("series");
+    // No series stored yet sums to zero
+    assert_eq!(client.get_or_compute(&key), 0);
+
+    client.set_series(&key, &vec![&env, 5u64, 6]);
+    assert_eq!(client.get_or_compute(&key), 11);
+    assert_eq!(client.cache_stats(), (0, 2));
+}
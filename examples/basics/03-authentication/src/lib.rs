@@ -22,8 +22,8 @@
 #![allow(deprecated)]
 
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, vec, Address, Env, Symbol,
-    Vec,
+    contract, contracterror, contractimpl, contracttype, symbol_short, vec, Address, Env, String,
+    Symbol, Vec,
 };
 use soroban_validation::*;
 
@@ -41,6 +41,18 @@ pub enum Role {
     User = 2,
 }
 
+/// Lower discriminants are more privileged, so a role satisfies any check
+/// for itself or a role below it.
+impl access_control::RoleType for Role {
+    fn grants(&self, required: &Self) -> bool {
+        (*self as u32) <= (*required as u32)
+    }
+
+    fn is_admin(&self) -> bool {
+        *self == Role::Admin
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Contract state
 // ---------------------------------------------------------------------------
@@ -64,24 +76,37 @@ pub enum ContractState {
 /// * `Admin`              -- the privileged admin address (instance storage).
 /// * `Balance(Address)`   -- per-account token balance (persistent storage).
 /// * `Allowance(from, spender)` -- spend allowance (persistent storage).
-/// * `UserRole(Address)`  -- role assigned to an address (persistent storage).
 /// * `TimeLock`           -- global unlock timestamp (instance storage).
 /// * `CooldownPeriod`     -- cooldown duration in seconds (instance storage).
 /// * `LastAction(Address)` -- last action timestamp per address (persistent storage).
-/// * `State`              -- current contract state (instance storage).
+/// * `Frozen(Address)`    -- compliance freeze flag per address (persistent storage).
+/// * `TotalSupply`        -- sum of all balances (instance storage).
+/// * `Name` / `TokenSymbol` / `Decimals` -- token metadata (instance storage).
+/// * `AllowlistMode`      -- whether the allowlist or the denylist is active (instance storage).
+/// * `Allowed(Address)`   -- membership in the allowlist (persistent storage).
+/// * `Denied(Address)`    -- membership in the denylist (persistent storage).
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
     Admin,
     Balance(Address),
     Allowance(Address, Address),
-    UserRole(Address),
     TimeLock,
     CooldownPeriod,
     LastAction(Address),
-    State,
+    Frozen(Address),
+    TotalSupply,
+    Name,
+    TokenSymbol,
+    Decimals,
+    AllowlistMode,
+    Allowed(Address),
+    Denied(Address),
 }
 
+/// Largest `decimals` value accepted by `initialize_metadata`.
+pub const MAX_DECIMALS: u32 = 18;
+
 // ---------------------------------------------------------------------------
 // Error type
 // ---------------------------------------------------------------------------
@@ -107,6 +132,14 @@ pub enum AuthError {
     InvalidState = 7,
     /// The caller does not have the required role.
     InsufficientRole = 8,
+    /// An argument is invalid (e.g. non-positive amount or self-transfer).
+    InvalidInput = 9,
+    /// The sender or recipient account has been frozen by the admin.
+    AccountFrozen = 10,
+    /// The sender or recipient is blocked by the allowlist / denylist.
+    NotAllowed = 11,
+    /// A counter or sum would overflow its integer type.
+    LimitExceeded = 12,
 }
 
 // ---------------------------------------------------------------------------
@@ -139,6 +172,10 @@ const CONTRACT_NS: Symbol = symbol_short!("auth");
 const ACTION_ADMIN: Symbol = symbol_short!("admin");
 const ACTION_AUDIT: Symbol = symbol_short!("audit");
 
+/// Allowances in this example never expire. The value still fills the
+/// `expiration_ledger` slot so `approve` events match the SEP-41 token shape.
+pub const ALLOWANCE_NO_EXPIRATION: u32 = u32::MAX;
+
 // ---------------------------------------------------------------------------
 // Contract
 // ---------------------------------------------------------------------------
@@ -178,6 +215,69 @@ impl AuthContract {
         env.storage().instance().get(&DataKey::Admin)
     }
 
+    // ==================== TOKEN METADATA ====================
+
+    /// Sets the token name, symbol and decimals (admin-only, once).
+    ///
+    /// Metadata is immutable: a second call returns `AlreadyInitialized`.
+    /// `decimals` above [`MAX_DECIMALS`] returns `InvalidInput`.
+    pub fn initialize_metadata(
+        env: Env,
+        admin: Address,
+        name: String,
+        symbol: String,
+        decimals: u32,
+    ) -> Result<(), AuthError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        if env.storage().instance().has(&DataKey::Decimals) {
+            return Err(AuthError::AlreadyInitialized);
+        }
+        if decimals > MAX_DECIMALS {
+            return Err(AuthError::InvalidInput);
+        }
+
+        env.storage().instance().set(&DataKey::Name, &name);
+        env.storage().instance().set(&DataKey::TokenSymbol, &symbol);
+        env.storage().instance().set(&DataKey::Decimals, &decimals);
+
+        // Audit trail for metadata initialization
+        env.events().publish(
+            (CONTRACT_NS, ACTION_AUDIT, admin),
+            AuditTrailEventData {
+                details: symbol_short!("metadata"),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Returns the token name. Panics if metadata has not been set.
+    pub fn name(env: Env) -> String {
+        env.storage()
+            .instance()
+            .get(&DataKey::Name)
+            .expect("metadata not initialized")
+    }
+
+    /// Returns the token symbol. Panics if metadata has not been set.
+    pub fn symbol(env: Env) -> String {
+        env.storage()
+            .instance()
+            .get(&DataKey::TokenSymbol)
+            .expect("metadata not initialized")
+    }
+
+    /// Returns the token decimals. Panics if metadata has not been set.
+    pub fn decimals(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Decimals)
+            .expect("metadata not initialized")
+    }
+
     // ==================== ADMIN-ONLY PATTERNS ====================
 
     /// Demonstrates an admin-only gate.
@@ -188,20 +288,11 @@ impl AuthContract {
     ///    random `Address` that they happen to control.
     pub fn admin_action(env: Env, admin: Address, value: u32) -> Result<u32, AuthError> {
         admin.require_auth();
-        let stored_admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .ok_or(AuthError::NotAdmin)?;
-
-        // Use shared validation pattern
-        if require_admin(stored_admin, admin.clone()).is_err() {
-            return Err(AuthError::NotAdmin);
-        }
+        Self::require_admin(&env, &admin)?;
 
-        // Log admin action
+        // Log admin action under the contract namespace like every other event
         env.events().publish(
-            (CONTRACT_NS, ACTION_ADMIN, admin),
+            (CONTRACT_NS, Symbol::new(&env, "admin_action"), admin),
             AdminActionEventData {
                 action: symbol_short!("action"),
                 timestamp: env.ledger().timestamp(),
@@ -219,16 +310,7 @@ impl AuthContract {
         amount: i128,
     ) -> Result<(), AuthError> {
         admin.require_auth();
-        let stored_admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .ok_or(AuthError::NotAdmin)?;
-
-        // Use shared validation pattern
-        if require_admin(stored_admin, admin.clone()).is_err() {
-            return Err(AuthError::NotAdmin);
-        }
+        Self::require_admin(&env, &admin)?;
 
         let old_balance: i128 = env
             .storage()
@@ -239,6 +321,7 @@ impl AuthContract {
         env.storage()
             .persistent()
             .set(&DataKey::Balance(user.clone()), &amount);
+        Self::adjust_total_supply(&env, amount - old_balance);
 
         // Audit trail for balance change
         env.events().publish(
@@ -249,6 +332,49 @@ impl AuthContract {
         Ok(())
     }
 
+    /// Create `amount` new tokens for `user` (admin-only).
+    pub fn mint(env: Env, admin: Address, user: Address, amount: i128) -> Result<(), AuthError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        if validate_amount(amount, 1, i128::MAX).is_err() {
+            return Err(AuthError::InvalidInput);
+        }
+
+        let balance = Self::get_balance(env.clone(), user.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::Balance(user.clone()), &(balance + amount));
+        Self::adjust_total_supply(&env, amount);
+
+        // SEP-41: topics ("mint", to), data amount
+        env.events().publish((symbol_short!("mint"), user), amount);
+
+        Ok(())
+    }
+
+    /// Destroy `amount` of `user`'s tokens (admin-only).
+    pub fn burn(env: Env, admin: Address, user: Address, amount: i128) -> Result<(), AuthError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        if validate_amount(amount, 1, i128::MAX).is_err() {
+            return Err(AuthError::InvalidInput);
+        }
+
+        let balance = Self::get_balance(env.clone(), user.clone());
+        if require_sufficient_balance(balance, amount).is_err() {
+            return Err(AuthError::InsufficientBalance);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::Balance(user.clone()), &(balance - amount));
+        Self::adjust_total_supply(&env, -amount);
+
+        // SEP-41: topics ("burn", from), data amount
+        env.events().publish((symbol_short!("burn"), user), amount);
+
+        Ok(())
+    }
+
     // ==================== SINGLE-ADDRESS AUTH PATTERN ====================
 
     /// Transfer tokens from `from` to `to`.
@@ -256,9 +382,23 @@ impl AuthContract {
     /// Security:
     /// - `from.require_auth()` ensures only the owner can debit their account.
     /// - The balance check prevents the sender from going negative.
+    /// - Non-positive amounts, self-transfers and transfers to this contract
+    ///   are rejected with `InvalidInput`. Soroban has no null address, so the
+    ///   contract's own address is the closest thing to a "burn" sink.
     pub fn transfer(env: Env, from: Address, to: Address, amount: i128) -> Result<(), AuthError> {
         from.require_auth();
 
+        // Use shared validation pattern
+        if validate_amount(amount, 1, i128::MAX).is_err() {
+            return Err(AuthError::InvalidInput);
+        }
+        if from == to || to == env.current_contract_address() {
+            return Err(AuthError::InvalidInput);
+        }
+        Self::require_not_frozen(&env, &from)?;
+        Self::require_not_frozen(&env, &to)?;
+        Self::require_allowed(&env, &from, &to)?;
+
         let from_balance: i128 = env
             .storage()
             .persistent()
@@ -278,10 +418,14 @@ impl AuthContract {
 
         env.storage()
             .persistent()
-            .set(&DataKey::Balance(from), &(from_balance - amount));
+            .set(&DataKey::Balance(from.clone()), &(from_balance - amount));
         env.storage()
             .persistent()
-            .set(&DataKey::Balance(to), &(to_balance + amount));
+            .set(&DataKey::Balance(to.clone()), &(to_balance + amount));
+
+        // SEP-41: topics ("transfer", from, to), data amount
+        env.events()
+            .publish((symbol_short!("transfer"), from, to), amount);
 
         Ok(())
     }
@@ -296,10 +440,57 @@ impl AuthContract {
         amount: i128,
     ) -> Result<(), AuthError> {
         from.require_auth();
+        Self::write_allowance(&env, from, spender, amount);
+        Ok(())
+    }
+
+    /// Raise the allowance of `spender` over `from`'s funds by `delta`.
+    ///
+    /// Unlike `approve`, this adjusts the current value instead of
+    /// overwriting it, so a spender cannot use the old allowance and then
+    /// the new one by front-running the update.
+    pub fn increase_allowance(
+        env: Env,
+        from: Address,
+        spender: Address,
+        delta: i128,
+    ) -> Result<(), AuthError> {
+        from.require_auth();
+        if delta < 0 {
+            return Err(AuthError::InvalidInput);
+        }
+
+        let current = Self::allowance(env.clone(), from.clone(), spender.clone());
+        let updated = current.checked_add(delta).ok_or(AuthError::InvalidInput)?;
+        Self::write_allowance(&env, from, spender, updated);
+        Ok(())
+    }
+
+    /// Lower the allowance of `spender` over `from`'s funds by `delta`,
+    /// stopping at zero.
+    pub fn decrease_allowance(
+        env: Env,
+        from: Address,
+        spender: Address,
+        delta: i128,
+    ) -> Result<(), AuthError> {
+        from.require_auth();
+        if delta < 0 {
+            return Err(AuthError::InvalidInput);
+        }
+
+        let current = Self::allowance(env.clone(), from.clone(), spender.clone());
+        let updated = current.saturating_sub(delta).max(0);
+        Self::write_allowance(&env, from, spender, updated);
+        Ok(())
+    }
+
+    /// Returns how much `spender` may still transfer from `from` (0 if none).
+    pub fn allowance(env: Env, from: Address, spender: Address) -> i128 {
         env.storage()
             .persistent()
-            .set(&DataKey::Allowance(from, spender), &amount);
-        Ok(())
+            .get(&DataKey::Allowance(from, spender))
+            .unwrap_or(0)
     }
 
     /// Transfer `amount` from `from` to `to` using the `spender` allowance.
@@ -316,6 +507,9 @@ impl AuthContract {
         amount: i128,
     ) -> Result<(), AuthError> {
         spender.require_auth();
+        Self::require_not_frozen(&env, &from)?;
+        Self::require_not_frozen(&env, &to)?;
+        Self::require_allowed(&env, &from, &to)?;
 
         let allowance: i128 = env
             .storage()
@@ -348,14 +542,100 @@ impl AuthContract {
             .set(&DataKey::Balance(from.clone()), &(from_balance - amount));
         env.storage()
             .persistent()
-            .set(&DataKey::Balance(to), &(to_balance + amount));
+            .set(&DataKey::Balance(to.clone()), &(to_balance + amount));
+        env.storage().persistent().set(
+            &DataKey::Allowance(from.clone(), spender),
+            &(allowance - amount),
+        );
+
+        // SEP-41: transfer_from emits the same event as a direct transfer
+        env.events()
+            .publish((symbol_short!("transfer"), from, to), amount);
+
+        Ok(())
+    }
+
+    // ==================== ACCOUNT FREEZE ====================
+
+    /// Freeze `user` so it can neither send nor receive transfers (admin-only).
+    pub fn freeze(env: Env, admin: Address, user: Address) -> Result<(), AuthError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        Self::set_frozen(&env, user, true);
+        Ok(())
+    }
+
+    /// Lift a freeze previously placed on `user` (admin-only).
+    pub fn unfreeze(env: Env, admin: Address, user: Address) -> Result<(), AuthError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        Self::set_frozen(&env, user, false);
+        Ok(())
+    }
+
+    /// Returns whether `user` is currently frozen.
+    pub fn is_frozen(env: Env, user: Address) -> bool {
         env.storage()
             .persistent()
-            .set(&DataKey::Allowance(from, spender), &(allowance - amount));
+            .get(&DataKey::Frozen(user))
+            .unwrap_or(false)
+    }
 
+    // ==================== ALLOWLIST / DENYLIST ====================
+
+    /// Switch between the allowlist (`true`) and the denylist (`false`)
+    /// (admin-only). Denylist is the default.
+    ///
+    /// The two lists are stored separately, so switching never turns
+    /// denylisted addresses into allowed ones; each list keeps its entries
+    /// for when its mode is active again.
+    pub fn set_allowlist_mode(env: Env, admin: Address, enabled: bool) -> Result<(), AuthError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let was_enabled = Self::is_allowlist_mode(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::AllowlistMode, &enabled);
+
+        // Audit trail with before/after state
+        env.events()
+            .publish((CONTRACT_NS, ACTION_AUDIT, admin), (was_enabled, enabled));
+        Ok(())
+    }
+
+    /// Add `addr` to the list for the current mode (admin-only).
+    pub fn add_to_list(env: Env, admin: Address, addr: Address) -> Result<(), AuthError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        Self::set_listed(&env, addr, true);
+        Ok(())
+    }
+
+    /// Remove `addr` from the list for the current mode (admin-only).
+    pub fn remove_from_list(env: Env, admin: Address, addr: Address) -> Result<(), AuthError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        Self::set_listed(&env, addr, false);
         Ok(())
     }
 
+    /// Returns whether the address list is in allowlist mode.
+    pub fn is_allowlist_mode(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::AllowlistMode)
+            .unwrap_or(false)
+    }
+
+    /// Returns whether `addr` is on the list for the current mode.
+    pub fn is_listed(env: Env, addr: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&Self::list_key(&env, addr))
+            .unwrap_or(false)
+    }
+
     // ==================== QUERY ====================
 
     /// Returns the balance for `user` (0 if never set).
@@ -366,6 +646,23 @@ impl AuthContract {
             .unwrap_or(0)
     }
 
+    /// Returns the balance of each address in `users`, in order (0 if never set).
+    pub fn balances_of(env: Env, users: Vec<Address>) -> Vec<i128> {
+        let mut balances = Vec::new(&env);
+        for user in users.iter() {
+            balances.push_back(Self::get_balance(env.clone(), user));
+        }
+        balances
+    }
+
+    /// Returns the sum of all balances.
+    pub fn total_supply(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TotalSupply)
+            .unwrap_or(0)
+    }
+
     // ==================== MULTI-SIG PATTERN ====================
 
     /// Demonstrates N-of-N multi-sig: every signer in the list must authorize.
@@ -373,11 +670,20 @@ impl AuthContract {
     /// The Soroban host collects authorizations before invoking the contract, so
     /// order does not matter. This function simply iterates the list calling
     /// `require_auth()` on each -- the host verifies all of them atomically.
-    pub fn multi_sig_action(_env: Env, signers: Vec<Address>, value: u32) -> u32 {
+    ///
+    /// Repeated addresses are only authorized and counted once. Returns
+    /// `LimitExceeded` if `value` plus the distinct signer count overflows.
+    pub fn multi_sig_action(env: Env, signers: Vec<Address>, value: u32) -> Result<u32, AuthError> {
+        let mut distinct: Vec<Address> = Vec::new(&env);
         for signer in signers.iter() {
-            signer.require_auth();
+            if !distinct.contains(&signer) {
+                signer.require_auth();
+                distinct.push_back(signer);
+            }
         }
-        value + signers.len()
+        value
+            .checked_add(distinct.len())
+            .ok_or(AuthError::LimitExceeded)
     }
 
     // ==================== SECURE OPERATION ====================
@@ -420,15 +726,8 @@ impl AuthContract {
         admin.require_auth();
         Self::require_admin(&env, &admin)?;
 
-        let old_role: Role = env
-            .storage()
-            .persistent()
-            .get(&DataKey::UserRole(account.clone()))
-            .unwrap_or(Role::User);
-
-        env.storage()
-            .persistent()
-            .set(&DataKey::UserRole(account.clone()), &role);
+        let old_role = Self::role_of(&env, &account);
+        access_control::set_role(&env, &account, role);
 
         // Emit audit event with before/after state
         env.events()
@@ -442,15 +741,8 @@ impl AuthContract {
         admin.require_auth();
         Self::require_admin(&env, &admin)?;
 
-        let old_role: Role = env
-            .storage()
-            .persistent()
-            .get(&DataKey::UserRole(account.clone()))
-            .unwrap_or(Role::User);
-
-        env.storage()
-            .persistent()
-            .remove(&DataKey::UserRole(account.clone()));
+        let old_role = Self::role_of(&env, &account);
+        access_control::remove_role::<Role>(&env, &account);
 
         // Emit audit event
         env.events()
@@ -461,20 +753,12 @@ impl AuthContract {
 
     /// Get the role of an address (returns User if not set).
     pub fn get_role(env: Env, account: Address) -> u32 {
-        env.storage()
-            .persistent()
-            .get(&DataKey::UserRole(account))
-            .unwrap_or(Role::User) as u32
+        Self::role_of(&env, &account) as u32
     }
 
     /// Check if an address has a specific role.
     pub fn has_role(env: Env, account: Address, role: Role) -> bool {
-        let user_role: Role = env
-            .storage()
-            .persistent()
-            .get(&DataKey::UserRole(account))
-            .unwrap_or(Role::User);
-        user_role as u32 <= role as u32
+        access_control::RoleType::grants(&Self::role_of(&env, &account), &role)
     }
 
     /// Admin-only action demonstrating role-based access control.
@@ -522,15 +806,9 @@ impl AuthContract {
         admin.require_auth();
         Self::require_admin(&env, &admin)?;
 
-        let old_time: u64 = env
-            .storage()
-            .instance()
-            .get(&DataKey::TimeLock)
-            .unwrap_or(0);
+        let old_time = timelock::unlock_time(&env, &DataKey::TimeLock);
 
-        env.storage()
-            .instance()
-            .set(&DataKey::TimeLock, &unlock_time);
+        timelock::set_unlock_time(&env, &DataKey::TimeLock, unlock_time);
 
         // Audit trail for timelock configuration
         env.events()
@@ -543,18 +821,12 @@ impl AuthContract {
     pub fn time_locked_action(env: Env, caller: Address) -> Result<u64, AuthError> {
         caller.require_auth();
 
-        let unlock_time: u64 = env
-            .storage()
-            .instance()
-            .get(&DataKey::TimeLock)
-            .unwrap_or(0);
-
-        let current_time = env.ledger().timestamp();
-        if current_time < unlock_time {
+        // Shared timelock helper: an unset lock counts as elapsed
+        if !timelock::is_elapsed(&env, &DataKey::TimeLock) {
             return Err(AuthError::TimeLocked);
         }
 
-        Ok(current_time)
+        Ok(env.ledger().timestamp())
     }
 
     /// Set the cooldown period (admin-only).
@@ -615,13 +887,14 @@ impl AuthContract {
         admin.require_auth();
         Self::require_admin(&env, &admin)?;
 
-        let old_state: ContractState = env
-            .storage()
-            .instance()
-            .get(&DataKey::State)
-            .unwrap_or(ContractState::Active);
+        let old_state = Self::current_state(&env);
 
-        env.storage().instance().set(&DataKey::State, &state);
+        match state {
+            ContractState::Active => pausable::unpause(&env),
+            ContractState::Paused => pausable::pause(&env),
+            // A state-set freeze has no expiry; it lasts until set back to Active
+            ContractState::Frozen => pausable::freeze(&env, u64::MAX),
+        }
 
         // Audit trail for state change
         env.events()
@@ -632,23 +905,14 @@ impl AuthContract {
 
     /// Get the current contract state.
     pub fn get_state(env: Env) -> u32 {
-        env.storage()
-            .instance()
-            .get(&DataKey::State)
-            .unwrap_or(ContractState::Active) as u32
+        Self::current_state(&env) as u32
     }
 
     /// Action that only works when the contract is Active.
     pub fn active_only_action(env: Env, caller: Address) -> Result<u64, AuthError> {
         caller.require_auth();
 
-        let state: ContractState = env
-            .storage()
-            .instance()
-            .get(&DataKey::State)
-            .unwrap_or(ContractState::Active);
-
-        if state != ContractState::Active {
+        if pausable::is_paused(&env) {
             return Err(AuthError::InvalidState);
         }
 
@@ -657,7 +921,23 @@ impl AuthContract {
 
     // ==================== HELPER METHODS ====================
 
-    /// Verify that the caller is the admin.
+    /// Store an allowance and emit the SEP-41 `approve` event for it.
+    fn write_allowance(env: &Env, from: Address, spender: Address, amount: i128) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Allowance(from.clone(), spender.clone()), &amount);
+
+        // SEP-41: topics ("approve", from, spender), data (amount, expiration_ledger)
+        env.events().publish(
+            (symbol_short!("approve"), from, spender),
+            (amount, ALLOWANCE_NO_EXPIRATION),
+        );
+    }
+
+    /// Verify that the caller is the stored admin.
+    ///
+    /// Every admin-only entry point goes through this helper so the check
+    /// cannot drift between functions. Callers must still `require_auth()`.
     fn require_admin(env: &Env, caller: &Address) -> Result<(), AuthError> {
         let admin: Address = env
             .storage()
@@ -672,21 +952,95 @@ impl AuthContract {
         Ok(())
     }
 
+    /// Apply a balance delta to the tracked total supply.
+    fn adjust_total_supply(env: &Env, delta: i128) {
+        let supply = Self::total_supply(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalSupply, &(supply + delta));
+    }
+
+    /// Reject the operation if `account` is frozen.
+    fn require_not_frozen(env: &Env, account: &Address) -> Result<(), AuthError> {
+        // Use shared validation pattern
+        if require_not_blacklisted(Self::is_frozen(env.clone(), account.clone())).is_err() {
+            return Err(AuthError::AccountFrozen);
+        }
+        Ok(())
+    }
+
+    /// Reject the transfer if the address list blocks either party: in
+    /// allowlist mode both must be listed, in denylist mode neither may be.
+    fn require_allowed(env: &Env, from: &Address, to: &Address) -> Result<(), AuthError> {
+        let allowlist = Self::is_allowlist_mode(env.clone());
+        for account in [from, to] {
+            if Self::is_listed(env.clone(), account.clone()) != allowlist {
+                return Err(AuthError::NotAllowed);
+            }
+        }
+        Ok(())
+    }
+
+    /// Storage key for `account` in the list of the current mode.
+    fn list_key(env: &Env, account: Address) -> DataKey {
+        if Self::is_allowlist_mode(env.clone()) {
+            DataKey::Allowed(account)
+        } else {
+            DataKey::Denied(account)
+        }
+    }
+
+    /// Store list membership for `account` and emit an audit event.
+    fn set_listed(env: &Env, account: Address, listed: bool) {
+        let was_listed = Self::is_listed(env.clone(), account.clone());
+        let key = Self::list_key(env, account.clone());
+        if listed {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+
+        // Audit trail with before/after state
+        env.events()
+            .publish((CONTRACT_NS, ACTION_AUDIT, account), (was_listed, listed));
+    }
+
+    /// Store the freeze flag for `account` and emit an audit event.
+    fn set_frozen(env: &Env, account: Address, frozen: bool) {
+        let was_frozen = Self::is_frozen(env.clone(), account.clone());
+        if frozen {
+            env.storage()
+                .persistent()
+                .set(&DataKey::Frozen(account.clone()), &true);
+        } else {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::Frozen(account.clone()));
+        }
+
+        // Audit trail with before/after state
+        env.events()
+            .publish((CONTRACT_NS, ACTION_AUDIT, account), (was_frozen, frozen));
+    }
+
     /// Verify that the caller has one of the required roles.
     fn require_role(env: &Env, caller: &Address, allowed_roles: &[Role]) -> Result<(), AuthError> {
-        let user_role: Role = env
-            .storage()
-            .persistent()
-            .get(&DataKey::UserRole(caller.clone()))
-            .unwrap_or(Role::User);
+        access_control::require_role(env, caller, allowed_roles)
+            .map_err(|_| AuthError::InsufficientRole)
+    }
 
-        for role in allowed_roles {
-            if user_role as u32 <= *role as u32 {
-                return Ok(());
-            }
+    /// Current circuit-breaker state, as tracked by the shared `pausable` module.
+    fn current_state(env: &Env) -> ContractState {
+        match pausable::state(env) {
+            pausable::PauseState::Active => ContractState::Active,
+            pausable::PauseState::Paused => ContractState::Paused,
+            pausable::PauseState::Frozen(_) => ContractState::Frozen,
         }
+    }
 
-        Err(AuthError::InsufficientRole)
+    /// Role assigned to `account`, defaulting to `User` when none is stored.
+    fn role_of(env: &Env, account: &Address) -> Role {
+        access_control::get_role(env, account).unwrap_or(Role::User)
     }
 }
 
@@ -69,7 +69,10 @@
 
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env, Symbol};
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, log, symbol_short, Address, Env, Symbol,
+    Vec,
+};
 
 /// Event-emitting contract demonstrating both basic emission and
 /// query-friendly topic design.
@@ -151,10 +154,63 @@ pub struct AuditTrailEventData {
     pub sequence: u32,
 }
 
+/// Payload for a transfer event that carries its own display precision.
+///
+/// `amount` is in the token's smallest unit; `decimals` tells an indexer
+/// where to put the decimal point (`amount / 10^decimals`) without a
+/// separate lookup of the token's metadata.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferMetaEventData {
+    /// Number of base units moved.
+    pub amount: i128,
+    /// Decimal places of the token the amount is denominated in.
+    pub decimals: u32,
+}
+
+/// Errors returned by the counter functions.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum CounterError {
+    /// `increment` would take the counter above `u32::MAX`.
+    Overflow = 1,
+    /// `decrement` would take the counter below zero.
+    Underflow = 2,
+}
+
+/// Payload of the counter's `("number", "inc" | "dec")` events.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CounterEventData {
+    /// Counter value after the change.
+    pub value: u32,
+    /// Amount the counter moved by.
+    pub step: u32,
+}
+
+/// A user profile published whole as event data.
+///
+/// Any `#[contracttype]` can ride as an event payload; consumers decode it
+/// back with `TryFromVal` after matching on topics.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Profile {
+    /// Display name.
+    pub name: Symbol,
+    /// Reputation level.
+    pub level: u32,
+    /// Whether the profile has been verified.
+    pub verified: bool,
+}
+
 // ---------------------------------------------------------------------------
 // Contract
 // ---------------------------------------------------------------------------
 
+/// Maximum number of topics the host accepts on a single contract event.
+pub const MAX_EVENT_TOPICS: u32 = 4;
+
 /// Namespace symbol used as the first topic of every event this contract emits.
 ///
 /// Using a shared namespace lets indexers filter all events from this contract
@@ -283,6 +339,24 @@ impl EventsContract {
         );
     }
 
+    // -----------------------------------------------------------------------
+    // Example 5 – Struct as event data (2 topics + custom type)
+    // -----------------------------------------------------------------------
+
+    /// Emit a profile event whose data is an entire [`Profile`] struct.
+    ///
+    /// **Topic layout (2 topics):**
+    /// | Index | Value           | Role              |
+    /// |-------|-----------------|-------------------|
+    /// | 0     | `"profile"`     | Event name        |
+    /// | 1     | `user: Address` | Indexed user      |
+    ///
+    /// **Data:** [`Profile`] `{ name, level, verified }`
+    pub fn emit_profile(env: Env, user: Address, profile: Profile) {
+        env.events()
+            .publish((symbol_short!("profile"), user), profile);
+    }
+
     // -----------------------------------------------------------------------
     // Simple helpers (kept for backward-compatibility)
     // -----------------------------------------------------------------------
@@ -310,17 +384,124 @@ impl EventsContract {
         }
     }
 
-    /// Increment a simple instance counter (used by integration tests).
-    pub fn increment(env: Env) {
-        let mut num: u32 = env
-            .storage()
+    /// Emit `n` sequentially numbered events for paging demonstrations.
+    ///
+    /// Each event carries its index both as topic[1] and as the data payload,
+    /// so a consumer reading a page can verify it got the expected slice.
+    ///
+    /// Off-chain query: filter topic[0] == "seq", then page through results.
+    pub fn emit_n(env: Env, n: u32) {
+        for i in 0..n {
+            env.events().publish((symbol_short!("seq"), i), i);
+        }
+    }
+
+    /// Emit a contract event and a diagnostic log line side by side.
+    ///
+    /// - `env.events().publish` produces a **contract event**: it is part of
+    ///   the transaction result, survives on-chain, and is what indexers see.
+    /// - `log!` produces a **diagnostic event**: it is only recorded when
+    ///   diagnostics are enabled (tests, simulation, debug builds), is not
+    ///   part of consensus, and is compiled out of release builds. Never rely
+    ///   on it for anything an off-chain consumer needs.
+    pub fn emit_with_log(env: Env, value: u32) {
+        log!(&env, "emit_with_log called with value", value);
+        env.events().publish((symbol_short!("logged"),), value);
+    }
+
+    /// Emit an event whose topics are built at runtime from `topics`.
+    ///
+    /// A tuple's topic count is fixed where it is written, but a runtime-built
+    /// `Vec` can grow past the documented limit of 4 without any warning, and
+    /// indexers (RPC topic filters match at most 4 segments) cannot filter on
+    /// the extra topics. Checking the count up front fails fast with a clear
+    /// message instead.
+    pub fn emit_with_topics(env: Env, topics: Vec<Symbol>, data: u32) {
+        if topics.len() > MAX_EVENT_TOPICS {
+            panic!("too many event topics: at most 4 allowed");
+        }
+        env.events().publish(topics, data);
+    }
+
+    /// Set how far `increment` and `decrement` move the counter (default 1).
+    ///
+    /// Panics on a zero step, which would make both calls silent no-ops.
+    pub fn set_step(env: Env, step: u32) {
+        if step == 0 {
+            panic!("Step must be non-zero");
+        }
+        env.storage().instance().set(&symbol_short!("step"), &step);
+    }
+
+    /// Return the current step.
+    pub fn get_step(env: Env) -> u32 {
+        env.storage()
             .instance()
-            .get(&symbol_short!("num"))
-            .unwrap_or(0);
-        num += 1;
+            .get(&symbol_short!("step"))
+            .unwrap_or(1)
+    }
+
+    /// Increment a simple instance counter by the step (used by integration tests).
+    ///
+    /// Emits `("number", "inc")` with [`CounterEventData`] `{ value, step }`.
+    /// Returns `Overflow`, leaving the counter and event stream untouched,
+    /// if the result would not fit in a `u32`.
+    pub fn increment(env: Env) -> Result<(), CounterError> {
+        let step = Self::get_step(env.clone());
+        let num = Self::get_number(env.clone())
+            .checked_add(step)
+            .ok_or(CounterError::Overflow)?;
+        env.storage().instance().set(&symbol_short!("num"), &num);
+        env.events().publish(
+            (symbol_short!("number"), symbol_short!("inc")),
+            CounterEventData { value: num, step },
+        );
+        Ok(())
+    }
+
+    /// Decrement the instance counter by the step.
+    ///
+    /// Emits `("number", "dec")` with [`CounterEventData`], mirroring
+    /// `increment`. Returns `Underflow` if the result would go below zero.
+    pub fn decrement(env: Env) -> Result<(), CounterError> {
+        let step = Self::get_step(env.clone());
+        let num = Self::get_number(env.clone())
+            .checked_sub(step)
+            .ok_or(CounterError::Underflow)?;
         env.storage().instance().set(&symbol_short!("num"), &num);
+        env.events().publish(
+            (symbol_short!("number"), symbol_short!("dec")),
+            CounterEventData { value: num, step },
+        );
+        Ok(())
+    }
+
+    /// Overwrite the instance counter.
+    ///
+    /// Together with `inc` and `dec`, the `("number", "set")` event makes the
+    /// counter fully reconstructible from its event stream: start at 0, take
+    /// the payload of each `set`, and add or subtract the `step` carried by
+    /// each `inc` / `dec`.
+    pub fn set_number(env: Env, value: u32) {
+        env.storage().instance().set(&symbol_short!("num"), &value);
+        env.events()
+            .publish((symbol_short!("number"), symbol_short!("set")), value);
+    }
+
+    /// Reset the instance counter to zero.
+    ///
+    /// Emits `("number", "reset")` rather than reusing `set`, so consumers can
+    /// tell a deliberate reset apart from an ordinary overwrite.
+    pub fn reset(env: Env) {
+        Self::reset_to(env, 0);
+    }
+
+    /// Reset the instance counter to `value`, emitting `("number", "reset")`
+    /// with the new value as data.
+    pub fn reset_to(env: Env, value: u32) {
+        env.storage().instance().set(&symbol_short!("num"), &value);
         env.events()
-            .publish((symbol_short!("number"), symbol_short!("inc")), num);
+            .publish((symbol_short!("number"), symbol_short!("reset")), value);
     }
 
     /// Return the current counter value.
@@ -351,6 +532,26 @@ impl EventsContract {
             .publish((symbol_short!("transfer"), from, to), amount);
     }
 
+    /// Emits a transfer event with the same topics as [`Self::emit_transfer`]
+    /// but a [`TransferMetaEventData`] payload, so the amount and the decimals
+    /// needed to display it travel together:
+    ///   topic[0] = "transfer"
+    ///   topic[1] = from
+    ///   topic[2] = to
+    ///   data     = { amount, decimals }
+    pub fn emit_transfer_with_meta(
+        env: Env,
+        from: Address,
+        to: Address,
+        amount: i128,
+        decimals: u32,
+    ) {
+        env.events().publish(
+            (symbol_short!("transfer"), from, to),
+            TransferMetaEventData { amount, decimals },
+        );
+    }
+
     /// Emits a namespaced event using a 3-topic hierarchy:
     ///   topic[0] = category (e.g. "defi")
     ///   topic[1] = action   (e.g. "swap")
@@ -8,10 +8,10 @@
 use super::*;
 use soroban_sdk::{
     symbol_short,
-    testutils::{Address as _, Events as _},
-    Address, Env, Symbol, TryFromVal,
+    testutils::{Address as _, Events as _, Logs as _},
+    vec, Address, Env, Symbol, TryFromVal, Val, Vec,
 };
-use soroban_validation::test_events::EventList;
+use soroban_validation::test_events::{assert_last_event, EventList};
 
 // ---------------------------------------------------------------------------
 // Helpers
@@ -24,6 +24,23 @@ fn make_env_and_client() -> (Env, Address, EventsContractClient<'static>) {
     (env, contract_id, client)
 }
 
+/// Return at most `len` events starting at index `start`, the way an indexer
+/// pages through a large result set. Out-of-range pages come back empty.
+fn collect_range(
+    env: &Env,
+    events: &EventList,
+    start: u32,
+    len: u32,
+) -> Vec<(Address, Vec<Val>, Val)> {
+    let total = events.len() as u32;
+    let end = start.saturating_add(len).min(total);
+    let mut page = Vec::new(env);
+    for i in start..end {
+        page.push_back(events.get(i).unwrap());
+    }
+    page
+}
+
 #[test]
 fn test_naming_convention_namespace_and_action_slots_are_stable() {
     let (env, _, client) = make_env_and_client();
@@ -392,6 +409,36 @@ fn test_emit_transfer_topic_layout() {
     assert_eq!(amount, 500);
 }
 
+#[test]
+fn test_emit_transfer_with_meta_struct_payload() {
+    let (env, _, client) = make_env_and_client();
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+
+    // 12.5 units of a 7-decimal token
+    client.emit_transfer_with_meta(&from, &to, &125_000_000, &7);
+
+    let events = EventList::new(&env, env.events().all());
+    assert_eq!(events.len(), 1);
+    let (_id, topics, data) = events.get(0).unwrap();
+
+    assert_eq!(topics.len(), 3);
+    let action: Symbol = Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap();
+    assert_eq!(action, symbol_short!("transfer"));
+    assert_eq!(
+        Address::try_from_val(&env, &topics.get(1).unwrap()).unwrap(),
+        from
+    );
+    assert_eq!(
+        Address::try_from_val(&env, &topics.get(2).unwrap()).unwrap(),
+        to
+    );
+
+    let payload = TransferMetaEventData::try_from_val(&env, &data).unwrap();
+    assert_eq!(payload.amount, 125_000_000);
+    assert_eq!(payload.decimals, 7);
+}
+
 #[test]
 fn test_emit_transfer_independent_senders_queryable() {
     // Verifies that multiple transfers can be distinguished by topic[1] (sender).
@@ -635,3 +682,387 @@ fn test_audit_trail_structured_data_payload() {
     assert_eq!(payload.timestamp, env.ledger().timestamp());
     assert_eq!(payload.sequence, env.ledger().sequence());
 }
+
+// ---------------------------------------------------------------------------
+// Struct payload event tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_emit_profile_struct_payload_round_trips() {
+    let (env, _, client) = make_env_and_client();
+
+    let user = Address::generate(&env);
+    let profile = Profile {
+        name: symbol_short!("alice"),
+        level: 7,
+        verified: true,
+    };
+    client.emit_profile(&user, &profile);
+
+    let events = EventList::new(&env, env.events().all());
+    assert_eq!(events.len(), 1);
+    let (_id, topics, data) = events.get(0).unwrap();
+
+    let t0: Symbol = Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap();
+    let t_user = Address::try_from_val(&env, &topics.get(1).unwrap()).unwrap();
+    assert_eq!(t0, symbol_short!("profile"));
+    assert_eq!(t_user, user);
+
+    let decoded = Profile::try_from_val(&env, &data).unwrap();
+    assert_eq!(decoded.name, profile.name);
+    assert_eq!(decoded.level, profile.level);
+    assert_eq!(decoded.verified, profile.verified);
+    assert_eq!(decoded, profile);
+}
+
+// ---------------------------------------------------------------------------
+// Paginated event reading
+// ---------------------------------------------------------------------------
+
+fn page_values(env: &Env, page: &Vec<(Address, Vec<Val>, Val)>) -> Vec<u32> {
+    let mut values = Vec::new(env);
+    for (_id, _topics, data) in page.iter() {
+        values.push_back(u32::try_from_val(env, &data).unwrap());
+    }
+    values
+}
+
+#[test]
+fn test_emit_n_emits_numbered_events() {
+    let (env, _, client) = make_env_and_client();
+    client.emit_n(&5);
+
+    let events = EventList::new(&env, env.events().all());
+    assert_eq!(events.len(), 5);
+    for (i, (_id, topics, data)) in events.iter().enumerate() {
+        let t0: Symbol = Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap();
+        let t1: u32 = u32::try_from_val(&env, &topics.get(1).unwrap()).unwrap();
+        assert_eq!(t0, symbol_short!("seq"));
+        assert_eq!(t1, i as u32);
+        assert_eq!(u32::try_from_val(&env, &data).unwrap(), i as u32);
+    }
+}
+
+#[test]
+fn test_collect_range_pages_through_events() {
+    let (env, _, client) = make_env_and_client();
+    client.emit_n(&7);
+    let events = EventList::new(&env, env.events().all());
+
+    let first = collect_range(&env, &events, 0, 3);
+    assert_eq!(page_values(&env, &first), vec![&env, 0u32, 1, 2]);
+
+    let second = collect_range(&env, &events, 3, 3);
+    assert_eq!(page_values(&env, &second), vec![&env, 3u32, 4, 5]);
+
+    // Partial final page
+    let tail = collect_range(&env, &events, 6, 3);
+    assert_eq!(page_values(&env, &tail), vec![&env, 6u32]);
+}
+
+#[test]
+fn test_collect_range_empty_tail_and_out_of_range() {
+    let (env, _, client) = make_env_and_client();
+    client.emit_n(&4);
+    let events = EventList::new(&env, env.events().all());
+
+    // Starting exactly at the end yields an empty page
+    assert_eq!(collect_range(&env, &events, 4, 2).len(), 0);
+    // Starting past the end, or with a zero length, also yields an empty page
+    assert_eq!(collect_range(&env, &events, 10, 2).len(), 0);
+    assert_eq!(collect_range(&env, &events, 1, 0).len(), 0);
+    // A huge length does not overflow and returns the remainder
+    assert_eq!(collect_range(&env, &events, 2, u32::MAX).len(), 2);
+}
+
+// ---------------------------------------------------------------------------
+// Topic count guard
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_emit_with_topics_at_maximum() {
+    let (env, _, client) = make_env_and_client();
+    let topics = vec![
+        &env,
+        symbol_short!("a"),
+        symbol_short!("b"),
+        symbol_short!("c"),
+        symbol_short!("d"),
+    ];
+    assert_eq!(topics.len(), MAX_EVENT_TOPICS);
+
+    client.emit_with_topics(&topics, &7);
+
+    let (_id, emitted, data) = EventList::new(&env, env.events().all()).last().unwrap();
+    assert_eq!(emitted.len(), MAX_EVENT_TOPICS);
+    for (i, topic) in topics.iter().enumerate() {
+        let got = Symbol::try_from_val(&env, &emitted.get(i as u32).unwrap()).unwrap();
+        assert_eq!(got, topic);
+    }
+    assert_eq!(u32::try_from_val(&env, &data).unwrap(), 7);
+}
+
+#[test]
+#[should_panic(expected = "too many event topics: at most 4 allowed")]
+fn test_emit_with_topics_above_limit_panics() {
+    let (env, _, client) = make_env_and_client();
+    let topics = vec![
+        &env,
+        symbol_short!("a"),
+        symbol_short!("b"),
+        symbol_short!("c"),
+        symbol_short!("d"),
+        symbol_short!("e"),
+    ];
+    client.emit_with_topics(&topics, &7);
+}
+
+// ---------------------------------------------------------------------------
+// Contract events vs diagnostic logs
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_contract_event_and_diagnostic_log_are_separate() {
+    let (env, contract_id, client) = make_env_and_client();
+    client.emit_with_log(&9);
+
+    // The contract event is the only entry in the event stream...
+    assert_eq!(EventList::new(&env, env.events().all()).len(), 1);
+    assert_last_event(&env, &contract_id, symbol_short!("logged"), 9);
+
+    // ...while the log! line shows up only in the debug log.
+    let logs = env.logs().all();
+    assert!(logs
+        .iter()
+        .any(|line| line.contains("emit_with_log called with value")));
+}
+
+// ---------------------------------------------------------------------------
+// Event-sourced state reconstruction
+// ---------------------------------------------------------------------------
+
+/// Append this contract's `("number", action)` events from the last
+/// invocation to `log` as `(action, amount)`: the new value for `set` and
+/// `reset`, the step for `inc` / `dec`. `env.events().all()` only holds the most recent
+/// invocation, so an indexer-style test collects after every call.
+fn collect_number_events(env: &Env, log: &mut Vec<(Symbol, u32)>) {
+    let events = EventList::new(env, env.events().all());
+    for (_id, topics, data) in events.iter() {
+        let t0 = Symbol::try_from_val(env, &topics.get(0).unwrap()).unwrap();
+        if t0 != symbol_short!("number") {
+            continue;
+        }
+        let action = Symbol::try_from_val(env, &topics.get(1).unwrap()).unwrap();
+        let amount = if action == symbol_short!("set") || action == symbol_short!("reset") {
+            u32::try_from_val(env, &data).unwrap()
+        } else {
+            CounterEventData::try_from_val(env, &data).unwrap().step
+        };
+        log.push_back((action, amount));
+    }
+}
+
+/// Rebuild the counter purely from its event stream, the way an indexer
+/// would, without ever reading contract storage.
+fn fold_number_events(log: &Vec<(Symbol, u32)>) -> u32 {
+    let mut number = 0u32;
+    for (action, amount) in log.iter() {
+        if action == symbol_short!("set") || action == symbol_short!("reset") {
+            number = amount;
+        } else if action == symbol_short!("inc") {
+            number += amount;
+        } else if action == symbol_short!("dec") {
+            number -= amount;
+        } else {
+            panic!("unexpected number event");
+        }
+    }
+    number
+}
+
+fn last_counter_data(env: &Env) -> CounterEventData {
+    let (_id, _topics, data) = EventList::new(env, env.events().all()).last().unwrap();
+    CounterEventData::try_from_val(env, &data).unwrap()
+}
+
+/// The action sits in topic 1, which `assert_last_event` does not cover.
+fn last_action(env: &Env) -> Symbol {
+    let (_id, topics, _data) = EventList::new(env, env.events().all()).last().unwrap();
+    Symbol::try_from_val(env, &topics.get(1).unwrap()).unwrap()
+}
+
+#[test]
+fn test_counter_events_carry_action_and_value() {
+    let (env, contract_id, client) = make_env_and_client();
+
+    client.set_number(&10);
+    assert_last_event(&env, &contract_id, symbol_short!("number"), 10);
+    assert_eq!(last_action(&env), symbol_short!("set"));
+
+    client.decrement();
+    assert_eq!(last_action(&env), symbol_short!("dec"));
+    assert_eq!(
+        last_counter_data(&env),
+        CounterEventData { value: 9, step: 1 }
+    );
+}
+
+#[test]
+fn test_counter_step_moves_value_and_is_in_payload() {
+    let (env, _, client) = make_env_and_client();
+    assert_eq!(client.get_step(), 1);
+
+    client.set_step(&5);
+    client.increment();
+    client.increment();
+    assert_eq!(last_action(&env), symbol_short!("inc"));
+    assert_eq!(
+        last_counter_data(&env),
+        CounterEventData { value: 10, step: 5 }
+    );
+    assert_eq!(client.get_number(), 10);
+
+    client.decrement();
+    assert_eq!(
+        last_counter_data(&env),
+        CounterEventData { value: 5, step: 5 }
+    );
+}
+
+#[test]
+fn test_reset_emits_reset_event_and_counting_continues() {
+    let (env, contract_id, client) = make_env_and_client();
+    client.set_number(&7);
+    client.increment();
+
+    client.reset();
+    assert_last_event(&env, &contract_id, symbol_short!("number"), 0);
+    assert_eq!(last_action(&env), symbol_short!("reset"));
+    assert_eq!(client.get_number(), 0);
+
+    client.increment();
+    assert_eq!(client.get_number(), 1);
+
+    client.reset_to(&100);
+    assert_last_event(&env, &contract_id, symbol_short!("number"), 100);
+    assert_eq!(last_action(&env), symbol_short!("reset"));
+    assert_eq!(client.get_number(), 100);
+
+    client.increment();
+    client.decrement();
+    client.increment();
+    assert_eq!(client.get_number(), 101);
+}
+
+#[test]
+fn test_reconstruct_counter_across_reset() {
+    let (env, _, client) = make_env_and_client();
+    let mut log = Vec::new(&env);
+
+    client.set_number(&9);
+    collect_number_events(&env, &mut log);
+    client.reset();
+    collect_number_events(&env, &mut log);
+    client.increment();
+    collect_number_events(&env, &mut log);
+    client.reset_to(&20);
+    collect_number_events(&env, &mut log);
+    client.decrement();
+    collect_number_events(&env, &mut log);
+
+    assert_eq!(log.get(1).unwrap(), (symbol_short!("reset"), 0));
+    assert_eq!(fold_number_events(&log), client.get_number());
+    assert_eq!(fold_number_events(&log), 19);
+}
+
+#[test]
+#[should_panic(expected = "Step must be non-zero")]
+fn test_counter_zero_step_panics() {
+    let (_env, _, client) = make_env_and_client();
+    client.set_step(&0);
+}
+
+#[test]
+fn test_assert_last_event_matches_counter_set() {
+    let (env, contract_id, client) = make_env_and_client();
+    client.set_number(&1);
+    assert_last_event(&env, &contract_id, symbol_short!("number"), 1);
+    client.set_number(&2);
+    assert_last_event(&env, &contract_id, symbol_short!("number"), 2);
+}
+
+#[test]
+#[should_panic(expected = "assert_last_event: no events were emitted")]
+fn test_assert_last_event_fails_without_events() {
+    let (env, contract_id, client) = make_env_and_client();
+    client.get_number();
+    assert_last_event(&env, &contract_id, symbol_short!("number"), 0);
+}
+
+#[test]
+#[should_panic(expected = "assert_last_event: wrong data")]
+fn test_assert_last_event_fails_on_wrong_data() {
+    let (env, contract_id, client) = make_env_and_client();
+    client.set_number(&3);
+    assert_last_event(&env, &contract_id, symbol_short!("number"), 5);
+}
+
+#[test]
+fn test_decrement_below_zero_returns_underflow() {
+    let (_env, _, client) = make_env_and_client();
+    assert_eq!(client.try_decrement(), Err(Ok(CounterError::Underflow)));
+    assert_eq!(client.get_number(), 0);
+
+    // A step larger than the current value also underflows.
+    client.set_number(&3);
+    client.set_step(&5);
+    assert_eq!(client.try_decrement(), Err(Ok(CounterError::Underflow)));
+    assert_eq!(client.get_number(), 3);
+}
+
+#[test]
+fn test_increment_at_max_returns_overflow() {
+    let (env, _, client) = make_env_and_client();
+    client.set_number(&(u32::MAX - 1));
+    client.increment();
+    assert_eq!(client.get_number(), u32::MAX);
+
+    assert_eq!(client.try_increment(), Err(Ok(CounterError::Overflow)));
+    assert_eq!(EventList::new(&env, env.events().all()).len(), 0);
+    assert_eq!(client.get_number(), u32::MAX);
+
+    // A step that would jump past the maximum is rejected too.
+    client.set_number(&(u32::MAX - 2));
+    client.set_step(&5);
+    assert_eq!(client.try_increment(), Err(Ok(CounterError::Overflow)));
+    assert_eq!(client.get_number(), u32::MAX - 2);
+}
+
+#[test]
+fn test_reconstruct_counter_from_events() {
+    let (env, _, client) = make_env_and_client();
+    let mut log = Vec::new(&env);
+
+    client.increment();
+    collect_number_events(&env, &mut log);
+    client.increment();
+    collect_number_events(&env, &mut log);
+    client.set_number(&40);
+    collect_number_events(&env, &mut log);
+    client.decrement();
+    collect_number_events(&env, &mut log);
+    client.increment();
+    collect_number_events(&env, &mut log);
+    client.decrement();
+    collect_number_events(&env, &mut log);
+    client.decrement();
+    collect_number_events(&env, &mut log);
+    // A step change emits nothing itself; the next events carry it.
+    client.set_step(&3);
+    client.increment();
+    collect_number_events(&env, &mut log);
+
+    assert_eq!(log.len(), 8);
+    assert_eq!(fold_number_events(&log), client.get_number());
+    assert_eq!(fold_number_events(&log), 41);
+}
@@ -1,6 +1,24 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, Address, Env};
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short, vec, xdr::ToXdr, Address,
+    Bytes, BytesN, Env, IntoVal, Symbol,
+};
+
+/// Storage keys for [`AuthContextContract`].
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    /// Next expected meta-transaction nonce for an ed25519 public key.
+    MetaNonce(BytesN<32>),
+}
+
+/// [`AuthContextContract::classify_caller`] result for a classic Stellar
+/// account (`G...` strkey).
+pub const CALLER_ACCOUNT: u32 = 0;
+/// [`AuthContextContract::classify_caller`] result for a contract
+/// (`C...` strkey).
+pub const CALLER_CONTRACT: u32 = 1;
 
 #[contract]
 pub struct AuthContextContract;
@@ -16,6 +34,23 @@ impl AuthContextContract {
         invoker
     }
 
+    /// Requires `caller`'s auth and reports whether it is a classic account
+    /// ([`CALLER_ACCOUNT`]) or a contract ([`CALLER_CONTRACT`]).
+    ///
+    /// The type is read from the first character of the address's strkey.
+    /// Unlike `Address::executable`, this also works for accounts that have
+    /// no ledger entry yet. Accounts authorize with a signature and contracts
+    /// through their `__check_auth` (or by being the direct caller), so
+    /// contracts sometimes need to know which one they are dealing with.
+    pub fn classify_caller(_env: Env, caller: Address) -> u32 {
+        caller.require_auth();
+        match caller.to_string().to_bytes().get(0) {
+            Some(b'G') => CALLER_ACCOUNT,
+            Some(b'C') => CALLER_CONTRACT,
+            _ => panic!("Unsupported address type"),
+        }
+    }
+
     /// Returns the address of this current contract being executed.
     pub fn get_current_address(env: Env) -> Address {
         env.current_contract_address()
@@ -53,6 +88,63 @@ impl AuthContextContract {
         user.require_auth();
         true
     }
+
+    /// Runs an action for `user` only while the ledger sequence is at or
+    /// below `valid_until_ledger`, returning the current sequence.
+    ///
+    /// `valid_until_ledger` is an argument, so it is part of the invocation
+    /// the user authorizes: a relayer cannot extend the deadline without
+    /// invalidating the authorization. This is the contract-level
+    /// counterpart of the `signature_expiration_ledger` every signed
+    /// `SorobanAuthorizationEntry` already carries.
+    pub fn execute_before(env: Env, user: Address, valid_until_ledger: u32) -> u32 {
+        user.require_auth();
+        let sequence = env.ledger().sequence();
+        if sequence > valid_until_ledger {
+            panic!("Authorization expired");
+        }
+        sequence
+    }
+
+    /// Executes `action` on behalf of `user`, submitted by a relayer.
+    ///
+    /// Instead of `require_auth`, the user proves consent with an ed25519
+    /// signature over [`Self::meta_payload`]: this contract's address, the
+    /// action and the user's current nonce. The nonce is then bumped, so the
+    /// same signature can never be accepted twice. Whoever submits the
+    /// transaction pays the fee; the user never has to.
+    ///
+    /// Panics if the signature does not verify.
+    pub fn execute_meta(env: Env, user: BytesN<32>, action: Symbol, signature: BytesN<64>) -> u64 {
+        let nonce = Self::get_nonce(env.clone(), user.clone());
+        let payload = Self::meta_payload(env.clone(), action.clone(), nonce);
+        env.crypto().ed25519_verify(&user, &payload, &signature);
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::MetaNonce(user.clone()), &(nonce + 1));
+
+        // The action itself would run here; the example just records it
+        env.events()
+            .publish((symbol_short!("meta"), user), (action, nonce));
+        nonce
+    }
+
+    /// Next nonce `user` must sign over.
+    pub fn get_nonce(env: Env, user: BytesN<32>) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::MetaNonce(user))
+            .unwrap_or(0)
+    }
+
+    /// The exact bytes a user signs to authorize `action` at `nonce`.
+    ///
+    /// Binding the contract address stops a signature from being replayed
+    /// against a different deployment.
+    pub fn meta_payload(env: Env, action: Symbol, nonce: u64) -> Bytes {
+        (env.current_contract_address(), action, nonce).to_xdr(&env)
+    }
 }
 
 /// A simple Proxy contract to demonstrate nested calls and how the auth
@@ -81,6 +173,102 @@ impl ProxyContract {
         // Return the user address to confirm success
         user
     }
+
+    /// Forwards `user`'s authorization two hops down: Proxy -> Middle ->
+    /// `target`'s `check_nested_auth`.
+    ///
+    /// All three contracts call `user.require_auth()`, so the user has to
+    /// authorize the whole tree of invocations, not just this entry point.
+    /// An authorization that stops at the middle hop is rejected by the
+    /// innermost `require_auth`.
+    pub fn proxy_forward(env: Env, middle: Address, target: Address, user: Address) -> bool {
+        user.require_auth();
+        MiddleContractClient::new(&env, &middle).forward(&target, &user)
+    }
+}
+
+/// The second hop in the [`ProxyContract::proxy_forward`] chain.
+#[contract]
+pub struct MiddleContract;
+
+#[contractimpl]
+impl MiddleContract {
+    /// Requires `user`'s auth for this hop, then calls `check_nested_auth`
+    /// on `target`, which requires it again for the innermost hop.
+    pub fn forward(env: Env, target: Address, user: Address) -> bool {
+        user.require_auth();
+        AuthContextContractClient::new(&env, &target).check_nested_auth(&user)
+    }
+}
+
+/// Errors returned by [`TargetContract`].
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum TargetError {
+    DivisionByZero = 1,
+}
+
+/// Errors returned by [`CallerContract`]. The callee's errors are mapped into
+/// this enum so the caller exposes a single, stable error surface.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum CallerError {
+    /// The target reported `TargetError::DivisionByZero`.
+    TargetDivisionByZero = 1,
+    /// The call failed for a reason the target did not report as a
+    /// `TargetError` (missing contract, host error, trap).
+    TargetCallFailed = 2,
+}
+
+/// A callee that can fail with a typed contract error.
+#[contract]
+pub struct TargetContract;
+
+#[contractimpl]
+impl TargetContract {
+    /// Divides `a` by `b`, returning `TargetError::DivisionByZero` when `b == 0`.
+    pub fn checked_div(_env: Env, a: u32, b: u32) -> Result<u32, TargetError> {
+        if b == 0 {
+            return Err(TargetError::DivisionByZero);
+        }
+        Ok(a / b)
+    }
+}
+
+/// Calls [`TargetContract`] two ways to contrast how callee failures surface.
+#[contract]
+pub struct CallerContract;
+
+#[contractimpl]
+impl CallerContract {
+    /// Calls the target with `env.try_invoke_contract`, which returns the
+    /// callee's failure instead of aborting, and maps it into `CallerError`.
+    pub fn call_checked(env: Env, target: Address, a: u32, b: u32) -> Result<u32, CallerError> {
+        let result = env.try_invoke_contract::<u32, TargetError>(
+            &target,
+            &Symbol::new(&env, "checked_div"),
+            vec![&env, a.into_val(&env), b.into_val(&env)],
+        );
+
+        match result {
+            // The call succeeded and the return value decoded as u32
+            Ok(Ok(value)) => Ok(value),
+            // The call succeeded but the return value had the wrong type
+            Ok(Err(_)) => Err(CallerError::TargetCallFailed),
+            // The callee returned one of its own contract errors
+            Err(Ok(TargetError::DivisionByZero)) => Err(CallerError::TargetDivisionByZero),
+            // Any other failure (no such contract, host error, panic)
+            Err(Err(_)) => Err(CallerError::TargetCallFailed),
+        }
+    }
+
+    /// Calls the target with the generated client. Any callee error aborts
+    /// the whole invocation; the caller never gets a chance to handle it.
+    pub fn call_unchecked(env: Env, target: Address, a: u32, b: u32) -> u32 {
+        TargetContractClient::new(&env, &target).checked_div(&a, &b)
+    }
 }
 
 #[cfg(test)]
@@ -1,5 +1,13 @@
+extern crate std;
+
 use super::*;
-use soroban_sdk::{testutils::Address as _, Env};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    testutils::{
+        Address as _, AuthorizedFunction, AuthorizedInvocation, Ledger, MockAuth, MockAuthInvoke,
+    },
+    Bytes, BytesN, Env,
+};
 
 #[test]
 fn test_get_invoker_success() {
@@ -25,6 +33,46 @@ fn test_get_invoker_unauthorized() {
     client.get_invoker(&user_address);
 }
 
+/// A classic account address derived from an ed25519 key, as a wallet would.
+fn account_address(env: &Env, seed: u8) -> Address {
+    use soroban_sdk::xdr::{AccountId, PublicKey, ScAddress, Uint256};
+    use soroban_sdk::TryFromVal;
+    let key = SigningKey::from_bytes(&[seed; 32]);
+    let account = ScAddress::Account(AccountId(PublicKey::PublicKeyTypeEd25519(Uint256(
+        key.verifying_key().to_bytes(),
+    ))));
+    Address::try_from_val(env, &account).unwrap()
+}
+
+#[test]
+fn test_classify_caller_account() {
+    let env = Env::default();
+    let client = AuthContextContractClient::new(&env, &env.register(AuthContextContract, ()));
+    let account = account_address(&env, 3);
+
+    env.mock_all_auths();
+    assert_eq!(client.classify_caller(&account), CALLER_ACCOUNT);
+    assert_eq!(env.auths()[0].0, account);
+}
+
+#[test]
+fn test_classify_caller_contract() {
+    let env = Env::default();
+    let client = AuthContextContractClient::new(&env, &env.register(AuthContextContract, ()));
+    let other_contract = env.register(TargetContract, ());
+
+    env.mock_all_auths();
+    assert_eq!(client.classify_caller(&other_contract), CALLER_CONTRACT);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
+fn test_classify_caller_requires_auth() {
+    let env = Env::default();
+    let client = AuthContextContractClient::new(&env, &env.register(AuthContextContract, ()));
+    client.classify_caller(&account_address(&env, 3));
+}
+
 #[test]
 fn test_get_current_address() {
     let env = Env::default();
@@ -133,3 +181,316 @@ fn test_proxy_call_unauthorized() {
     // No mock_all_auths
     proxy_client.proxy_call(&contract_id, &user_address);
 }
+
+// ---------------------------------------------------------------------------
+// Three-contract auth forwarding (User -> Proxy -> Middle -> Target)
+// ---------------------------------------------------------------------------
+
+struct Chain {
+    user: Address,
+    proxy: Address,
+    middle: Address,
+    target: Address,
+}
+
+fn setup_chain(env: &Env) -> Chain {
+    Chain {
+        user: Address::generate(env),
+        proxy: env.register(ProxyContract, ()),
+        middle: env.register(MiddleContract, ()),
+        target: env.register(AuthContextContract, ()),
+    }
+}
+
+#[test]
+fn test_proxy_forward_with_full_auth_tree() {
+    let env = Env::default();
+    let c = setup_chain(&env);
+    let proxy = ProxyContractClient::new(&env, &c.proxy);
+
+    // Exactly the tree the user signs: each nested call is a sub-invocation
+    // of the one above it.
+    proxy
+        .mock_auths(&[MockAuth {
+            address: &c.user,
+            invoke: &MockAuthInvoke {
+                contract: &c.proxy,
+                fn_name: "proxy_forward",
+                args: (&c.middle, &c.target, &c.user).into_val(&env),
+                sub_invokes: &[MockAuthInvoke {
+                    contract: &c.middle,
+                    fn_name: "forward",
+                    args: (&c.target, &c.user).into_val(&env),
+                    sub_invokes: &[MockAuthInvoke {
+                        contract: &c.target,
+                        fn_name: "check_nested_auth",
+                        args: (&c.user,).into_val(&env),
+                        sub_invokes: &[],
+                    }],
+                }],
+            },
+        }])
+        .proxy_forward(&c.middle, &c.target, &c.user);
+
+    // The recorded authorization is the same three-level tree
+    let innermost = AuthorizedInvocation {
+        function: AuthorizedFunction::Contract((
+            c.target.clone(),
+            Symbol::new(&env, "check_nested_auth"),
+            (&c.user,).into_val(&env),
+        )),
+        sub_invocations: std::vec![],
+    };
+    let middle = AuthorizedInvocation {
+        function: AuthorizedFunction::Contract((
+            c.middle.clone(),
+            Symbol::new(&env, "forward"),
+            (&c.target, &c.user).into_val(&env),
+        )),
+        sub_invocations: std::vec![innermost],
+    };
+    assert_eq!(
+        env.auths(),
+        std::vec![(
+            c.user.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    c.proxy.clone(),
+                    Symbol::new(&env, "proxy_forward"),
+                    (&c.middle, &c.target, &c.user).into_val(&env),
+                )),
+                sub_invocations: std::vec![middle],
+            }
+        )]
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
+fn test_proxy_forward_without_innermost_auth_fails() {
+    let env = Env::default();
+    let c = setup_chain(&env);
+    let proxy = ProxyContractClient::new(&env, &c.proxy);
+
+    // The user authorized Proxy and Middle but not Target's
+    // `check_nested_auth`, so the innermost `require_auth` fails.
+    proxy
+        .mock_auths(&[MockAuth {
+            address: &c.user,
+            invoke: &MockAuthInvoke {
+                contract: &c.proxy,
+                fn_name: "proxy_forward",
+                args: (&c.middle, &c.target, &c.user).into_val(&env),
+                sub_invokes: &[MockAuthInvoke {
+                    contract: &c.middle,
+                    fn_name: "forward",
+                    args: (&c.target, &c.user).into_val(&env),
+                    sub_invokes: &[],
+                }],
+            },
+        }])
+        .proxy_forward(&c.middle, &c.target, &c.user);
+}
+
+// ---------------------------------------------------------------------------
+// Ledger-bounded authorization
+// ---------------------------------------------------------------------------
+
+/// Calls `execute_before` with a `MockAuth` covering exactly these arguments,
+/// the way a wallet would sign the invocation including its deadline.
+fn execute_before_authorized(
+    env: &Env,
+    client: &AuthContextContractClient<'_>,
+    user: &Address,
+    valid_until: u32,
+) -> u32 {
+    client
+        .mock_auths(&[MockAuth {
+            address: user,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "execute_before",
+                args: (user, valid_until).into_val(env),
+                sub_invokes: &[],
+            },
+        }])
+        .execute_before(user, &valid_until)
+}
+
+#[test]
+fn test_execute_before_within_range() {
+    let env = Env::default();
+    let client = AuthContextContractClient::new(&env, &env.register(AuthContextContract, ()));
+    let user = Address::generate(&env);
+
+    env.ledger().set_sequence_number(100);
+    assert_eq!(execute_before_authorized(&env, &client, &user, 110), 100);
+
+    // The bound is inclusive
+    env.ledger().set_sequence_number(110);
+    assert_eq!(execute_before_authorized(&env, &client, &user, 110), 110);
+}
+
+#[test]
+#[should_panic(expected = "Authorization expired")]
+fn test_execute_before_after_expiry() {
+    let env = Env::default();
+    let client = AuthContextContractClient::new(&env, &env.register(AuthContextContract, ()));
+    let user = Address::generate(&env);
+
+    env.ledger().set_sequence_number(100);
+    execute_before_authorized(&env, &client, &user, 110);
+
+    env.ledger().set_sequence_number(111);
+    execute_before_authorized(&env, &client, &user, 110);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
+fn test_execute_before_deadline_cannot_be_extended() {
+    let env = Env::default();
+    let client = AuthContextContractClient::new(&env, &env.register(AuthContextContract, ()));
+    let user = Address::generate(&env);
+    env.ledger().set_sequence_number(200);
+
+    // The user authorized a deadline of 110; submitting 1000 instead does
+    // not match the authorized arguments.
+    client
+        .mock_auths(&[MockAuth {
+            address: &user,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "execute_before",
+                args: (&user, 110u32).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .execute_before(&user, &1000);
+}
+
+// ---------------------------------------------------------------------------
+// Cross-contract error propagation
+// ---------------------------------------------------------------------------
+
+fn setup_caller(env: &Env) -> (CallerContractClient<'_>, Address) {
+    let target_id = env.register(TargetContract, ());
+    let caller_id = env.register(CallerContract, ());
+    (CallerContractClient::new(env, &caller_id), target_id)
+}
+
+#[test]
+fn test_call_checked_returns_target_value() {
+    let env = Env::default();
+    let (caller, target) = setup_caller(&env);
+
+    assert_eq!(caller.call_checked(&target, &10, &2), 5);
+}
+
+#[test]
+fn test_call_checked_maps_target_error() {
+    let env = Env::default();
+    let (caller, target) = setup_caller(&env);
+
+    // The callee's DivisionByZero comes back as the caller's own error code
+    let result = caller.try_call_checked(&target, &10, &0);
+    assert_eq!(result, Err(Ok(CallerError::TargetDivisionByZero)));
+}
+
+#[test]
+fn test_call_checked_maps_missing_contract() {
+    let env = Env::default();
+    let (caller, _target) = setup_caller(&env);
+    let not_a_contract = env.register(AuthContextContract, ());
+
+    // AuthContextContract has no `checked_div`, so the host call fails
+    let result = caller.try_call_checked(&not_a_contract, &10, &2);
+    assert_eq!(result, Err(Ok(CallerError::TargetCallFailed)));
+}
+
+#[test]
+fn test_call_unchecked_returns_target_value() {
+    let env = Env::default();
+    let (caller, target) = setup_caller(&env);
+
+    assert_eq!(caller.call_unchecked(&target, &9, &3), 3);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn test_call_unchecked_aborts_on_target_error() {
+    let env = Env::default();
+    let (caller, target) = setup_caller(&env);
+
+    // The generated client panics on the callee's error, aborting the caller
+    caller.call_unchecked(&target, &10, &0);
+}
+
+// ---------------------------------------------------------------------------
+// Signature-based meta-transactions
+// ---------------------------------------------------------------------------
+
+fn setup_meta(env: &Env) -> (AuthContextContractClient<'_>, SigningKey, BytesN<32>) {
+    let contract_id = env.register(AuthContextContract, ());
+    let client = AuthContextContractClient::new(env, &contract_id);
+    let key = SigningKey::from_bytes(&[7u8; 32]);
+    let public = BytesN::from_array(env, &key.verifying_key().to_bytes());
+    (client, key, public)
+}
+
+fn sign(env: &Env, key: &SigningKey, payload: &Bytes) -> BytesN<64> {
+    let msg: std::vec::Vec<u8> = payload.iter().collect();
+    BytesN::from_array(env, &key.sign(&msg).to_bytes())
+}
+
+#[test]
+fn test_execute_meta_with_valid_signature() {
+    let env = Env::default();
+    let (client, key, user) = setup_meta(&env);
+    let action = symbol_short!("claim");
+
+    let payload = client.meta_payload(&action, &client.get_nonce(&user));
+    let signature = sign(&env, &key, &payload);
+
+    // No mock_all_auths: the signature alone authorizes the call
+    assert_eq!(client.execute_meta(&user, &action, &signature), 0);
+    assert_eq!(client.get_nonce(&user), 1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Crypto, InvalidInput)")]
+fn test_execute_meta_replay_rejected() {
+    let env = Env::default();
+    let (client, key, user) = setup_meta(&env);
+    let action = symbol_short!("claim");
+
+    let signature = sign(&env, &key, &client.meta_payload(&action, &0));
+    client.execute_meta(&user, &action, &signature);
+    // The nonce has moved on, so the same signature no longer verifies
+    client.execute_meta(&user, &action, &signature);
+}
+
+#[test]
+#[should_panic(expected = "Error(Crypto, InvalidInput)")]
+fn test_execute_meta_wrong_signer_rejected() {
+    let env = Env::default();
+    let (client, _key, user) = setup_meta(&env);
+    let action = symbol_short!("claim");
+
+    let attacker = SigningKey::from_bytes(&[9u8; 32]);
+    let signature = sign(&env, &attacker, &client.meta_payload(&action, &0));
+    client.execute_meta(&user, &action, &signature);
+}
+
+#[test]
+#[should_panic(expected = "Error(Crypto, InvalidInput)")]
+fn test_execute_meta_signature_bound_to_action() {
+    let env = Env::default();
+    let (client, key, user) = setup_meta(&env);
+
+    let signature = sign(
+        &env,
+        &key,
+        &client.meta_payload(&symbol_short!("claim"), &0),
+    );
+    client.execute_meta(&user, &symbol_short!("drain"), &signature);
+}
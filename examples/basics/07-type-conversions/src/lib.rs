@@ -1,7 +1,8 @@
 #![no_std]
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, Address, Bytes, Env, IntoVal, Map, String,
-    Symbol, TryFromVal, Val, Vec,
+    contract, contracterror, contractimpl, contracttype,
+    xdr::{FromXdr, ToXdr},
+    Address, Bytes, Env, IntoVal, Map, String, Symbol, TryFromVal, Val, Vec,
 };
 
 /// Custom error types for conversion operations.
@@ -44,6 +45,23 @@ pub struct Config {
     pub features: Vec<Symbol>,
 }
 
+/// Spending limit used to show how a `#[contracttype]` enum crosses the ABI.
+///
+/// Unit variants encode as a one-element `Vec` holding the variant name;
+/// tuple variants append their fields, e.g. `Capped(5)` → `[Capped, 5]`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Limit {
+    Unlimited,
+    Capped(i64),
+}
+
+/// Largest scale supported by the decimal helpers (`10^38` still fits in `i128`).
+const MAX_DECIMALS: u32 = 38;
+
+/// Digit alphabet used by `to_base` / `from_base` (lower-case hex).
+const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
 #[contract]
 pub struct TypeConversionsContract;
 
@@ -424,6 +442,293 @@ impl TypeConversionsContract {
         let val: Val = input.into_val(&env);
         u32::try_from_val(&env, &val).unwrap_or(0)
     }
+
+    /// Renders `value` as a string in the given `base` (2 through 16).
+    ///
+    /// Digits are produced least-significant first, so each one is inserted
+    /// at the front of a `Bytes` buffer. The finished bytes are then copied
+    /// into a `String`. Bases above 10 use lower-case letters.
+    ///
+    /// # Panics
+    /// Panics with `"UnsupportedConversion"` for a base outside `2..=16`.
+    pub fn to_base(env: Env, value: u64, base: u32) -> String {
+        if !(2..=16).contains(&base) {
+            panic!("UnsupportedConversion");
+        }
+
+        let mut digits = Bytes::new(&env);
+        let mut remaining = value;
+        loop {
+            let digit = (remaining % base as u64) as usize;
+            digits.insert(0, DIGITS[digit]);
+            remaining /= base as u64;
+            if remaining == 0 {
+                break;
+            }
+        }
+
+        // u64::MAX in base 2 is the longest case: 64 digits.
+        let mut buf = [0u8; 64];
+        let len = digits.len() as usize;
+        digits.copy_into_slice(&mut buf[..len]);
+        String::from_bytes(&env, &buf[..len])
+    }
+
+    /// Parses a string produced by `to_base` (or typed by hand) back into a `u64`.
+    ///
+    /// Upper- and lower-case letters are both accepted for bases above 10.
+    ///
+    /// # Errors
+    /// * `UnsupportedConversion` — base outside `2..=16`
+    /// * `InvalidStringFormat`   — empty, too long, or a digit not valid in `base`
+    /// * `NumericOverflow`       — the value does not fit in a `u64`
+    pub fn from_base(_env: Env, s: String, base: u32) -> Result<u64, ConversionError> {
+        if !(2..=16).contains(&base) {
+            return Err(ConversionError::UnsupportedConversion);
+        }
+
+        let len = s.len() as usize;
+        if len == 0 || len > 64 {
+            return Err(ConversionError::InvalidStringFormat);
+        }
+        let mut buf = [0u8; 64];
+        s.copy_into_slice(&mut buf[..len]);
+
+        let mut acc: u64 = 0;
+        for b in buf.iter().take(len) {
+            let digit = match b {
+                b'0'..=b'9' => (b - b'0') as u32,
+                b'a'..=b'f' => (b - b'a') as u32 + 10,
+                b'A'..=b'F' => (b - b'A') as u32 + 10,
+                _ => return Err(ConversionError::InvalidStringFormat),
+            };
+            if digit >= base {
+                return Err(ConversionError::InvalidStringFormat);
+            }
+            acc = acc
+                .checked_mul(base as u64)
+                .and_then(|v| v.checked_add(digit as u64))
+                .ok_or(ConversionError::NumericOverflow)?;
+        }
+
+        Ok(acc)
+    }
+
+    /// Parses a decimal string such as `"12.34"` into a scaled integer.
+    ///
+    /// With `decimals = 2`, `"12.34"` becomes `1234`, `"12"` becomes `1200`
+    /// and `"0.5"` becomes `50`. A leading `-` is allowed. This is how token
+    /// amounts are usually stored on-chain: an `i128` plus a fixed scale.
+    ///
+    /// # Errors
+    /// * `InvalidStringFormat` — not of the form `[-]digits[.digits]`, or more
+    ///   fractional digits than `decimals`
+    /// * `NumericOverflow`     — the scaled value does not fit in `i128`, or
+    ///   `decimals` exceeds 38
+    pub fn parse_decimal(_env: Env, s: String, decimals: u32) -> Result<i128, ConversionError> {
+        if decimals > MAX_DECIMALS {
+            return Err(ConversionError::NumericOverflow);
+        }
+
+        // Sign + 39 integer digits + point + 38 fractional digits
+        let len = s.len() as usize;
+        if len == 0 || len > 80 {
+            return Err(ConversionError::InvalidStringFormat);
+        }
+        let mut buf = [0u8; 80];
+        s.copy_into_slice(&mut buf[..len]);
+
+        let (negative, start) = if buf[0] == b'-' {
+            (true, 1usize)
+        } else {
+            (false, 0usize)
+        };
+
+        let mut acc: i128 = 0;
+        let mut int_digits = 0u32;
+        let mut frac_digits = 0u32;
+        let mut seen_point = false;
+        for b in buf.iter().take(len).skip(start) {
+            match b {
+                b'.' if !seen_point => seen_point = true,
+                b'0'..=b'9' => {
+                    if seen_point {
+                        frac_digits += 1;
+                        if frac_digits > decimals {
+                            return Err(ConversionError::InvalidStringFormat);
+                        }
+                    } else {
+                        int_digits += 1;
+                    }
+                    acc = acc
+                        .checked_mul(10)
+                        .and_then(|v| v.checked_add((b - b'0') as i128))
+                        .ok_or(ConversionError::NumericOverflow)?;
+                }
+                _ => return Err(ConversionError::InvalidStringFormat),
+            }
+        }
+        // Both "." and a trailing "12." are malformed
+        if int_digits == 0 || (seen_point && frac_digits == 0) {
+            return Err(ConversionError::InvalidStringFormat);
+        }
+
+        // Pad the missing fractional digits, e.g. "0.5" with 2 decimals -> 50
+        let scaled = acc
+            .checked_mul(10i128.pow(decimals - frac_digits))
+            .ok_or(ConversionError::NumericOverflow)?;
+        Ok(if negative { -scaled } else { scaled })
+    }
+
+    /// Formats a scaled integer as a decimal string; the inverse of `parse_decimal`.
+    ///
+    /// All `decimals` fractional digits are written, so `format_decimal(50, 2)`
+    /// is `"0.50"`. With `decimals = 0` no decimal point is emitted.
+    ///
+    /// # Panics
+    /// Panics with `"NumericOverflow"` if `decimals` exceeds 38.
+    pub fn format_decimal(env: Env, value: i128, decimals: u32) -> String {
+        if decimals > MAX_DECIMALS {
+            panic!("NumericOverflow");
+        }
+
+        // Fill from the right: fractional digits, the point, then the
+        // integer part (at least one digit), then the sign.
+        let mut buf = [0u8; 48];
+        let mut pos = buf.len();
+        let mut remaining = value.unsigned_abs();
+        for _ in 0..decimals {
+            pos -= 1;
+            buf[pos] = b'0' + (remaining % 10) as u8;
+            remaining /= 10;
+        }
+        if decimals > 0 {
+            pos -= 1;
+            buf[pos] = b'.';
+        }
+        loop {
+            pos -= 1;
+            buf[pos] = b'0' + (remaining % 10) as u8;
+            remaining /= 10;
+            if remaining == 0 {
+                break;
+            }
+        }
+        if value < 0 {
+            pos -= 1;
+            buf[pos] = b'-';
+        }
+
+        String::from_bytes(&env, &buf[pos..])
+    }
+
+    /// Builds a `Map` from a list of key/value pairs.
+    ///
+    /// `Map::set` overwrites, so when a key appears more than once the last
+    /// pair in `pairs` wins. The resulting map is ordered by key, not by the
+    /// input order.
+    pub fn vec_to_map(env: Env, pairs: Vec<(Symbol, i64)>) -> Map<Symbol, i64> {
+        let mut map = Map::new(&env);
+        for (key, value) in pairs.iter() {
+            map.set(key, value);
+        }
+        map
+    }
+
+    /// Flattens a `Map` into a list of key/value pairs in key order.
+    pub fn map_to_vec(env: Env, m: Map<Symbol, i64>) -> Vec<(Symbol, i64)> {
+        let mut pairs = Vec::new(&env);
+        for (key, value) in m.iter() {
+            pairs.push_back((key, value));
+        }
+        pairs
+    }
+
+    /// Encodes an `Option<i64>` as a raw `Val`.
+    ///
+    /// `Some(v)` is encoded exactly like `v`; `None` becomes the host's `Void`
+    /// value. There is no extra tag, which is why an `Option<T>` argument is
+    /// ABI-compatible with a plain `T` argument when a value is present.
+    pub fn encode_option(env: Env, present: bool, value: i64) -> Val {
+        let option: Option<i64> = if present { Some(value) } else { None };
+        option.into_val(&env)
+    }
+
+    /// Decodes a `Val` produced by `encode_option` back into `(present, value)`.
+    ///
+    /// `value` is 0 when `present` is `false`.
+    ///
+    /// # Panics
+    /// Panics with `"UnsupportedConversion"` if `v` is neither `Void` nor an `i64`.
+    pub fn decode_option(env: Env, v: Val) -> (bool, i64) {
+        match Option::<i64>::try_from_val(&env, &v) {
+            Ok(Some(value)) => (true, value),
+            Ok(None) => (false, 0),
+            Err(_) => panic!("UnsupportedConversion"),
+        }
+    }
+
+    /// Encodes a [`Limit`] as a raw `Val` (`Capped(value)` when `capped`).
+    pub fn encode_limit(env: Env, capped: bool, value: i64) -> Val {
+        let limit = if capped {
+            Limit::Capped(value)
+        } else {
+            Limit::Unlimited
+        };
+        limit.into_val(&env)
+    }
+
+    /// Decodes a `Val` produced by `encode_limit` back into `(capped, value)`.
+    ///
+    /// # Panics
+    /// Panics with `"UnsupportedConversion"` if `v` is not a valid `Limit`.
+    pub fn decode_limit(env: Env, v: Val) -> (bool, i64) {
+        match Limit::try_from_val(&env, &v) {
+            Ok(Limit::Capped(value)) => (true, value),
+            Ok(Limit::Unlimited) => (false, 0),
+            Err(_) => panic!("UnsupportedConversion"),
+        }
+    }
+
+    /// Serializes an `Address` to its XDR bytes (an `ScVal::Address`).
+    ///
+    /// Account addresses encode to 44 bytes and contract addresses to 40.
+    pub fn address_to_bytes(env: Env, addr: Address) -> Bytes {
+        addr.to_xdr(&env)
+    }
+
+    /// Deserializes XDR bytes produced by `address_to_bytes` back to an `Address`.
+    ///
+    /// The host traps on malformed XDR rather than returning an error, so the
+    /// fixed-size layout is checked first to turn truncated or corrupted
+    /// input into a recoverable `InvalidAddress`.
+    ///
+    /// # Errors
+    /// * `InvalidAddress` — the bytes are not an XDR-encoded account or contract address
+    pub fn bytes_to_address(env: Env, b: Bytes) -> Result<Address, ConversionError> {
+        // ScVal::Address (18), then ScAddress::Account (0) + PublicKey::Ed25519 (0)
+        // or ScAddress::Contract (1), each discriminant a big-endian u32.
+        const ACCOUNT_HEADER: [u8; 12] = [0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0];
+        const CONTRACT_HEADER: [u8; 8] = [0, 0, 0, 18, 0, 0, 0, 1];
+
+        let mut header = [0u8; 12];
+        let well_formed = match b.len() {
+            44 => {
+                b.slice(0..12).copy_into_slice(&mut header);
+                header == ACCOUNT_HEADER
+            }
+            40 => {
+                b.slice(0..8).copy_into_slice(&mut header[..8]);
+                header[..8] == CONTRACT_HEADER
+            }
+            _ => false,
+        };
+        if !well_formed {
+            return Err(ConversionError::InvalidAddress);
+        }
+
+        Address::from_xdr(&env, &b).map_err(|_| ConversionError::InvalidAddress)
+    }
 }
 
 #[cfg(test)]
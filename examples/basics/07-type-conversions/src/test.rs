@@ -5,8 +5,8 @@
 
 use super::*;
 use soroban_sdk::{
-    symbol_short, testutils::Address as _, Address, Bytes, Env, IntoVal, Map, String, Symbol, Val,
-    Vec,
+    symbol_short, testutils::Address as _, vec, Address, Bytes, Env, IntoVal, Map, String, Symbol,
+    Val, Vec,
 };
 
 fn setup(env: &Env) -> TypeConversionsContractClient<'_> {
@@ -418,6 +418,352 @@ fn test_val_roundtrip() {
     assert_eq!(sum_result, 300);
 }
 
+// ── to_base / from_base ───────────────────────────────────────────────────────
+
+#[test]
+fn test_base_roundtrip_binary_octal_hex() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    let value = 2024u64;
+    let cases = [(2u32, "11111101000"), (8, "3750"), (16, "7e8")];
+    for (base, expected) in cases {
+        let rendered = client.to_base(&value, &base);
+        assert_eq!(rendered, String::from_str(&env, expected));
+        assert_eq!(client.from_base(&rendered, &base), value);
+    }
+}
+
+#[test]
+fn test_base_edge_values() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    assert_eq!(client.to_base(&0, &2), String::from_str(&env, "0"));
+    let max_hex = client.to_base(&u64::MAX, &16);
+    assert_eq!(max_hex, String::from_str(&env, "ffffffffffffffff"));
+    assert_eq!(client.from_base(&max_hex, &16), u64::MAX);
+    assert_eq!(client.to_base(&u64::MAX, &2).len(), 64);
+    // Upper-case hex is accepted on input
+    assert_eq!(client.from_base(&String::from_str(&env, "7E8"), &16), 2024);
+}
+
+#[test]
+fn test_base_rejects_unsupported_base() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    assert!(client.try_to_base(&10, &1).is_err());
+    assert!(client.try_to_base(&10, &17).is_err());
+    assert_eq!(
+        client.try_from_base(&String::from_str(&env, "10"), &17),
+        Err(Ok(ConversionError::UnsupportedConversion))
+    );
+}
+
+#[test]
+fn test_from_base_rejects_illegal_digits() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    for (input, base) in [("102", 2u32), ("8", 8), ("g", 16), ("", 10), ("-1", 10)] {
+        assert_eq!(
+            client.try_from_base(&String::from_str(&env, input), &base),
+            Err(Ok(ConversionError::InvalidStringFormat))
+        );
+    }
+    // One digit past u64::MAX
+    assert_eq!(
+        client.try_from_base(&String::from_str(&env, "10000000000000000"), &16),
+        Err(Ok(ConversionError::NumericOverflow))
+    );
+}
+
+// ── parse_decimal / format_decimal ────────────────────────────────────────────
+
+#[test]
+fn test_decimal_roundtrip() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    let cases = [("12.34", 1234i128), ("0.50", 50), ("-7.05", -705)];
+    for (text, scaled) in cases {
+        let input = String::from_str(&env, text);
+        assert_eq!(client.parse_decimal(&input, &2), scaled);
+        assert_eq!(client.format_decimal(&scaled, &2), input);
+    }
+}
+
+#[test]
+fn test_parse_decimal_pads_missing_fraction() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    assert_eq!(client.parse_decimal(&String::from_str(&env, "0.5"), &2), 50);
+    assert_eq!(
+        client.parse_decimal(&String::from_str(&env, "12"), &2),
+        1200
+    );
+    assert_eq!(client.parse_decimal(&String::from_str(&env, "12"), &0), 12);
+    // 7-decimal Stellar asset precision
+    assert_eq!(
+        client.parse_decimal(&String::from_str(&env, "1.5"), &7),
+        15_000_000
+    );
+}
+
+#[test]
+fn test_parse_decimal_rejects_over_precise_input() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    assert_eq!(
+        client.try_parse_decimal(&String::from_str(&env, "12.345"), &2),
+        Err(Ok(ConversionError::InvalidStringFormat))
+    );
+}
+
+#[test]
+fn test_parse_decimal_rejects_malformed_input() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    for text in ["", "-", ".5", "12.", "1.2.3", "1,5", "abc"] {
+        assert_eq!(
+            client.try_parse_decimal(&String::from_str(&env, text), &2),
+            Err(Ok(ConversionError::InvalidStringFormat))
+        );
+    }
+    assert_eq!(
+        client.try_parse_decimal(&String::from_str(&env, "1"), &39),
+        Err(Ok(ConversionError::NumericOverflow))
+    );
+}
+
+#[test]
+fn test_format_decimal_edge_values() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    assert_eq!(
+        client.format_decimal(&0, &2),
+        String::from_str(&env, "0.00")
+    );
+    assert_eq!(
+        client.format_decimal(&5, &3),
+        String::from_str(&env, "0.005")
+    );
+    assert_eq!(
+        client.format_decimal(&1234, &0),
+        String::from_str(&env, "1234")
+    );
+    assert_eq!(
+        client.format_decimal(&i128::MIN, &38),
+        String::from_str(&env, "-1.70141183460469231731687303715884105728")
+    );
+}
+
+// ── vec_to_map / map_to_vec ───────────────────────────────────────────────────
+
+#[test]
+fn test_vec_map_roundtrip() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    // Already in key order, so the round trip is exact
+    let pairs = vec![
+        &env,
+        (symbol_short!("a"), 1i64),
+        (symbol_short!("b"), -2i64),
+        (symbol_short!("c"), 3i64),
+    ];
+    let map = client.vec_to_map(&pairs);
+    assert_eq!(map.len(), 3);
+    assert_eq!(map.get(symbol_short!("b")), Some(-2));
+    assert_eq!(client.map_to_vec(&map), pairs);
+}
+
+#[test]
+fn test_vec_to_map_last_duplicate_wins() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    let pairs = vec![
+        &env,
+        (symbol_short!("x"), 1i64),
+        (symbol_short!("y"), 2i64),
+        (symbol_short!("x"), 3i64),
+    ];
+    let map = client.vec_to_map(&pairs);
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get(symbol_short!("x")), Some(3));
+    assert_eq!(
+        client.map_to_vec(&map),
+        vec![&env, (symbol_short!("x"), 3i64), (symbol_short!("y"), 2i64)]
+    );
+}
+
+// ── Option / enum through Val ─────────────────────────────────────────────────
+
+#[test]
+fn test_option_roundtrip_through_val() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    let some = client.encode_option(&true, &42);
+    // Some(v) has the same encoding as v itself
+    assert_eq!(i64::try_from_val(&env, &some), Ok(42));
+    assert_eq!(client.decode_option(&some), (true, 42));
+
+    let none = client.encode_option(&false, &42);
+    assert!(none.is_void());
+    assert_eq!(client.decode_option(&none), (false, 0));
+}
+
+#[test]
+fn test_decode_option_rejects_wrong_type() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    let not_i64: Val = symbol_short!("nope").into_val(&env);
+    assert!(client.try_decode_option(&not_i64).is_err());
+}
+
+#[test]
+fn test_enum_roundtrip_through_val() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    let capped = client.encode_limit(&true, &42);
+    assert_eq!(Limit::try_from_val(&env, &capped), Ok(Limit::Capped(42)));
+    assert_eq!(client.decode_limit(&capped), (true, 42));
+
+    let unlimited = client.encode_limit(&false, &42);
+    // Unit variants are a one-element vector holding the variant name
+    let encoded = Vec::<Val>::try_from_val(&env, &unlimited).unwrap();
+    assert_eq!(encoded.len(), 1);
+    assert_eq!(
+        Symbol::try_from_val(&env, &encoded.get(0).unwrap()),
+        Ok(Symbol::new(&env, "Unlimited"))
+    );
+    assert_eq!(client.decode_limit(&unlimited), (false, 0));
+
+    let not_limit: Val = 7u32.into_val(&env);
+    assert!(client.try_decode_limit(&not_limit).is_err());
+}
+
+// ── address_to_bytes / bytes_to_address ───────────────────────────────────────
+
+#[test]
+fn test_address_bytes_roundtrip() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    let account = Address::from_str(
+        &env,
+        "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
+    );
+    let bytes = client.address_to_bytes(&account);
+    assert_eq!(bytes.len(), 44);
+    assert_eq!(client.bytes_to_address(&bytes), account);
+
+    // Contract addresses use the shorter 40-byte layout
+    let contract = Address::generate(&env);
+    let bytes = client.address_to_bytes(&contract);
+    assert_eq!(bytes.len(), 40);
+    assert_eq!(client.bytes_to_address(&bytes), contract);
+}
+
+#[test]
+fn test_bytes_to_address_rejects_truncated_bytes() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    let bytes = client.address_to_bytes(&Address::generate(&env));
+    let truncated = bytes.slice(0..bytes.len() - 1);
+    assert_eq!(
+        client.try_bytes_to_address(&truncated),
+        Err(Ok(ConversionError::InvalidAddress))
+    );
+    assert_eq!(
+        client.try_bytes_to_address(&Bytes::new(&env)),
+        Err(Ok(ConversionError::InvalidAddress))
+    );
+}
+
+#[test]
+fn test_bytes_to_address_rejects_other_xdr_values() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    let mut bytes = client.address_to_bytes(&Address::generate(&env));
+    // Flip the ScVal discriminant so the bytes no longer describe an address
+    bytes.set(3, 0);
+    assert_eq!(
+        client.try_bytes_to_address(&bytes),
+        Err(Ok(ConversionError::InvalidAddress))
+    );
+}
+
+// ── property-style checks ─────────────────────────────────────────────────────
+
+/// Deterministic xorshift64* generator; `no_std` rules out the `rand` crate and
+/// a fixed seed keeps failures reproducible.
+struct XorShift(u64);
+
+impl XorShift {
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    fn next_i128(&mut self) -> i128 {
+        (((self.next_u64() as u128) << 64) | self.next_u64() as u128) as i128
+    }
+}
+
+#[test]
+fn test_property_convert_numbers_u32_range() {
+    let env = Env::default();
+    let client = setup(&env);
+    let mut rng = XorShift(0x5eed_cafe_f00d_d00d);
+
+    // Edges plus random values at several magnitudes: full i128, around the
+    // u32 range, and small numbers near zero.
+    let mut samples = [-1i128, 0, 1, u32::MAX as i128, u32::MAX as i128 + 1].to_vec();
+    for _ in 0..64 {
+        samples.push(rng.next_i128());
+        samples.push(rng.next_i128() >> 88);
+        samples.push(rng.next_i128() >> 118);
+    }
+
+    for value in samples {
+        let in_range = (0..=u32::MAX as i128).contains(&value);
+        let result = client.try_convert_numbers(&value, &1u32);
+        assert_eq!(result.is_ok(), in_range, "value {}", value);
+        if in_range {
+            assert_eq!(result.unwrap().unwrap(), value);
+        }
+    }
+}
+
+#[test]
+fn test_property_val_roundtrip_is_identity() {
+    let env = Env::default();
+    let client = setup(&env);
+    let mut rng = XorShift(0x0123_4567_89ab_cdef);
+
+    for value in [0u32, 1, u32::MAX] {
+        assert_eq!(client.val_roundtrip(&value), value);
+    }
+    for _ in 0..128 {
+        let value = rng.next_u64() as u32;
+        assert_eq!(client.val_roundtrip(&value), value);
+    }
+}
+
 // ── integration ───────────────────────────────────────────────────────────────
 
 #[test]
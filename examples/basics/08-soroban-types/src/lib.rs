@@ -21,9 +21,21 @@
 #![no_std]
 
 use soroban_sdk::{
-    contract, contractimpl, symbol_short, Address, Bytes, BytesN, Env, String, Symbol,
+    contract, contractimpl, contracttype, symbol_short,
+    xdr::{FromXdr, ToXdr},
+    Address, Bytes, BytesN, Env, Map, String, Symbol, Vec,
 };
 
+/// A user profile combining several Soroban types, used for the XDR demo.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Profile {
+    pub owner: Address,
+    pub username: String,
+    pub avatar_hash: BytesN<32>,
+    pub level: u32,
+}
+
 /// Contract demonstrating Soroban-specific types.
 #[contract]
 pub struct SorobanTypesContract;
@@ -97,6 +109,18 @@ impl SorobanTypesContract {
         bytes.len()
     }
 
+    /// Return `len` bytes of `b` starting at `start`.
+    ///
+    /// `Bytes::slice` traps with an opaque host error on a bad range, so the
+    /// bounds are checked up front to fail with a readable message instead.
+    pub fn slice_bytes(_env: Env, b: Bytes, start: u32, len: u32) -> Bytes {
+        let end = match start.checked_add(len) {
+            Some(end) if end <= b.len() => end,
+            _ => panic!("slice out of range"),
+        };
+        b.slice(start..end)
+    }
+
     // -----------------------------------------------------------------------
     // BytesN Type Demonstrations
     // -----------------------------------------------------------------------
@@ -134,6 +158,30 @@ impl SorobanTypesContract {
         Bytes::from_slice(&env, fixed.to_array().as_slice())
     }
 
+    /// Split a 32-byte hash into its first and second 16-byte halves.
+    ///
+    /// The lengths are part of the types, so no runtime bounds checks are
+    /// needed: copying through fixed-size arrays cannot go out of range.
+    pub fn split_hash(env: Env, hash: BytesN<32>) -> (BytesN<16>, BytesN<16>) {
+        let bytes = hash.to_array();
+        let mut first = [0u8; 16];
+        let mut second = [0u8; 16];
+        first.copy_from_slice(&bytes[..16]);
+        second.copy_from_slice(&bytes[16..]);
+        (
+            BytesN::from_array(&env, &first),
+            BytesN::from_array(&env, &second),
+        )
+    }
+
+    /// Join two 16-byte halves back into a 32-byte value; the inverse of `split_hash`.
+    pub fn join_halves(env: Env, a: BytesN<16>, b: BytesN<16>) -> BytesN<32> {
+        let mut joined = [0u8; 32];
+        joined[..16].copy_from_slice(&a.to_array());
+        joined[16..].copy_from_slice(&b.to_array());
+        BytesN::from_array(&env, &joined)
+    }
+
     // -----------------------------------------------------------------------
     // Symbol Type Demonstrations
     // -----------------------------------------------------------------------
@@ -222,6 +270,25 @@ impl SorobanTypesContract {
         String::from_bytes(&env, &buf[..total])
     }
 
+    /// Join `parts` into one String with `sep` between each pair.
+    ///
+    /// Works as a string builder: each piece is appended to a growable
+    /// `Bytes` buffer with `String::to_bytes`, and the buffer is converted
+    /// back with `String::from`. Unlike `concatenate_strings` there is no
+    /// fixed-size stack buffer, so the result length is not capped.
+    /// An empty `parts` gives an empty String; a single part is returned as-is.
+    pub fn join_strings(env: Env, parts: Vec<String>, sep: String) -> String {
+        let sep = sep.to_bytes();
+        let mut out = Bytes::new(&env);
+        for (i, part) in parts.iter().enumerate() {
+            if i > 0 {
+                out.append(&sep);
+            }
+            out.append(&part.to_bytes());
+        }
+        String::from(out)
+    }
+
     // -----------------------------------------------------------------------
     // Cross-Type Demonstrations
     // -----------------------------------------------------------------------
@@ -284,6 +351,88 @@ impl SorobanTypesContract {
         username.len() + bio.len()
     }
 
+    // -----------------------------------------------------------------------
+    // Map Type Demonstrations
+    // -----------------------------------------------------------------------
+
+    /// Register `username` for `addr` in a two-way registry.
+    ///
+    /// Two `Map`s are kept in instance storage — username → address and
+    /// address → username — and always updated together so lookups in either
+    /// direction agree. Each username and each address can be registered once.
+    pub fn register(env: Env, username: Symbol, addr: Address) {
+        addr.require_auth();
+
+        let mut names: Map<Symbol, Address> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("names"))
+            .unwrap_or_else(|| Map::new(&env));
+        let mut owners: Map<Address, Symbol> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("owners"))
+            .unwrap_or_else(|| Map::new(&env));
+
+        if names.contains_key(username.clone()) {
+            panic!("username already taken");
+        }
+        if owners.contains_key(addr.clone()) {
+            panic!("address already registered");
+        }
+
+        names.set(username.clone(), addr.clone());
+        owners.set(addr, username);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("names"), &names);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("owners"), &owners);
+    }
+
+    /// Look up the address registered for `username`.
+    pub fn resolve(env: Env, username: Symbol) -> Option<Address> {
+        let names: Map<Symbol, Address> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("names"))
+            .unwrap_or_else(|| Map::new(&env));
+        names.get(username)
+    }
+
+    /// Look up the username registered for `addr`.
+    pub fn reverse(env: Env, addr: Address) -> Option<Symbol> {
+        let owners: Map<Address, Symbol> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("owners"))
+            .unwrap_or_else(|| Map::new(&env));
+        owners.get(addr)
+    }
+
+    // -----------------------------------------------------------------------
+    // XDR Serialization
+    // -----------------------------------------------------------------------
+
+    /// Serialize a profile to XDR bytes.
+    ///
+    /// The result is an `ScVal` map keyed by field name, the same encoding the
+    /// host uses in storage and events, so off-chain tools can decode it with
+    /// any Stellar XDR library.
+    pub fn serialize_profile(env: Env, profile: Profile) -> Bytes {
+        profile.to_xdr(&env)
+    }
+
+    /// Reconstruct a profile from XDR bytes produced by `serialize_profile`.
+    ///
+    /// Bytes that are not valid XDR make the host trap; valid XDR of the wrong
+    /// shape panics with `"invalid profile XDR"`. Either way the call fails
+    /// instead of returning a half-decoded profile.
+    pub fn deserialize_profile(env: Env, b: Bytes) -> Profile {
+        Profile::from_xdr(&env, &b).unwrap_or_else(|_| panic!("invalid profile XDR"))
+    }
+
     /// Type validation examples.
     pub fn validate_types(_env: Env, _addr: Address, _sym: Symbol, text: String) -> bool {
         // Validate string is a reasonable length.
@@ -1,7 +1,9 @@
 //! Tests for Soroban Types demonstration.
 
 use super::*;
-use soroban_sdk::{symbol_short, testutils::Address as _, Bytes, BytesN, String, Symbol};
+use soroban_sdk::{
+    symbol_short, testutils::Address as _, vec, xdr::ToXdr, Bytes, BytesN, String, Symbol, Vec,
+};
 
 // ---------------------------------------------------------------------------
 // Address Tests
@@ -299,3 +301,211 @@ fn test_bytesn_always_32_bytes() {
     assert_eq!(client.get_bytes_length(&h1), 32);
     assert_eq!(client.get_bytes_length(&h2), 32);
 }
+
+#[test]
+fn test_join_strings_with_separator() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, SorobanTypesContract);
+    let client = SorobanTypesContractClient::new(&env, &contract_id);
+
+    let parts = vec![
+        &env,
+        String::from_str(&env, "alpha"),
+        String::from_str(&env, "beta"),
+        String::from_str(&env, "gamma"),
+    ];
+    let sep = String::from_str(&env, ", ");
+    assert_eq!(
+        client.join_strings(&parts, &sep),
+        String::from_str(&env, "alpha, beta, gamma")
+    );
+}
+
+#[test]
+fn test_join_strings_single_and_empty() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, SorobanTypesContract);
+    let client = SorobanTypesContractClient::new(&env, &contract_id);
+    let sep = String::from_str(&env, ", ");
+
+    let single = vec![&env, String::from_str(&env, "only")];
+    assert_eq!(
+        client.join_strings(&single, &sep),
+        String::from_str(&env, "only")
+    );
+
+    let empty: Vec<String> = Vec::new(&env);
+    assert_eq!(
+        client.join_strings(&empty, &sep),
+        String::from_str(&env, "")
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Slicing and Joining Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_split_and_join_hash() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, SorobanTypesContract);
+    let client = SorobanTypesContractClient::new(&env, &contract_id);
+
+    let mut raw = [0u8; 32];
+    for (i, byte) in raw.iter_mut().enumerate() {
+        *byte = i as u8;
+    }
+    let hash = BytesN::from_array(&env, &raw);
+
+    let (first, second) = client.split_hash(&hash);
+    assert_eq!(first.to_array()[0], 0);
+    assert_eq!(first.to_array()[15], 15);
+    assert_eq!(second.to_array()[0], 16);
+    assert_eq!(second.to_array()[15], 31);
+
+    assert_eq!(client.join_halves(&first, &second), hash);
+    // Order matters
+    assert_ne!(client.join_halves(&second, &first), hash);
+}
+
+#[test]
+fn test_slice_bytes() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, SorobanTypesContract);
+    let client = SorobanTypesContractClient::new(&env, &contract_id);
+
+    let data = Bytes::from_slice(&env, b"soroban");
+    assert_eq!(
+        client.slice_bytes(&data, &2, &3),
+        Bytes::from_slice(&env, b"rob")
+    );
+    assert_eq!(client.slice_bytes(&data, &0, &7), data);
+    assert!(client.slice_bytes(&data, &7, &0).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "slice out of range")]
+fn test_slice_bytes_out_of_range() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, SorobanTypesContract);
+    let client = SorobanTypesContractClient::new(&env, &contract_id);
+
+    let data = Bytes::from_slice(&env, b"soroban");
+    client.slice_bytes(&data, &5, &3);
+}
+
+#[test]
+#[should_panic(expected = "slice out of range")]
+fn test_slice_bytes_overflowing_range() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, SorobanTypesContract);
+    let client = SorobanTypesContractClient::new(&env, &contract_id);
+
+    let data = Bytes::from_slice(&env, b"soroban");
+    client.slice_bytes(&data, &1, &u32::MAX);
+}
+
+// ---------------------------------------------------------------------------
+// Map Registry Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_registry_forward_and_reverse_lookup() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, SorobanTypesContract);
+    let client = SorobanTypesContractClient::new(&env, &contract_id);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.register(&symbol_short!("alice"), &alice);
+    client.register(&symbol_short!("bob"), &bob);
+
+    assert_eq!(client.resolve(&symbol_short!("alice")), Some(alice.clone()));
+    assert_eq!(client.resolve(&symbol_short!("bob")), Some(bob.clone()));
+    assert_eq!(client.reverse(&alice), Some(symbol_short!("alice")));
+    assert_eq!(client.reverse(&bob), Some(symbol_short!("bob")));
+}
+
+#[test]
+fn test_registry_unknown_entries() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, SorobanTypesContract);
+    let client = SorobanTypesContractClient::new(&env, &contract_id);
+
+    assert_eq!(client.resolve(&symbol_short!("nobody")), None);
+    assert_eq!(client.reverse(&Address::generate(&env)), None);
+}
+
+#[test]
+#[should_panic(expected = "username already taken")]
+fn test_registry_rejects_duplicate_username() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, SorobanTypesContract);
+    let client = SorobanTypesContractClient::new(&env, &contract_id);
+
+    client.register(&symbol_short!("alice"), &Address::generate(&env));
+    client.register(&symbol_short!("alice"), &Address::generate(&env));
+}
+
+#[test]
+#[should_panic(expected = "address already registered")]
+fn test_registry_rejects_second_name_for_address() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, SorobanTypesContract);
+    let client = SorobanTypesContractClient::new(&env, &contract_id);
+
+    let alice = Address::generate(&env);
+    client.register(&symbol_short!("alice"), &alice);
+    client.register(&symbol_short!("alice2"), &alice);
+}
+
+// ---------------------------------------------------------------------------
+// XDR Serialization Tests
+// ---------------------------------------------------------------------------
+
+fn sample_profile(env: &Env) -> Profile {
+    Profile {
+        owner: Address::generate(env),
+        username: String::from_str(env, "alice"),
+        avatar_hash: BytesN::from_array(env, &[7u8; 32]),
+        level: 3,
+    }
+}
+
+#[test]
+fn test_profile_xdr_roundtrip() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, SorobanTypesContract);
+    let client = SorobanTypesContractClient::new(&env, &contract_id);
+
+    let profile = sample_profile(&env);
+    let bytes = client.serialize_profile(&profile);
+    assert!(!bytes.is_empty());
+    assert_eq!(client.deserialize_profile(&bytes), profile);
+}
+
+#[test]
+fn test_profile_xdr_truncated_bytes_fail() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, SorobanTypesContract);
+    let client = SorobanTypesContractClient::new(&env, &contract_id);
+
+    let bytes = client.serialize_profile(&sample_profile(&env));
+    let truncated = bytes.slice(0..bytes.len() / 2);
+    assert!(client.try_deserialize_profile(&truncated).is_err());
+}
+
+#[test]
+#[should_panic(expected = "invalid profile XDR")]
+fn test_profile_xdr_wrong_shape_fails() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, SorobanTypesContract);
+    let client = SorobanTypesContractClient::new(&env, &contract_id);
+
+    // Well-formed XDR, but a Symbol rather than a Profile map
+    let bytes = symbol_short!("alice").to_xdr(&env);
+    client.deserialize_profile(&bytes);
+}
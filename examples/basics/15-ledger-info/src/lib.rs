@@ -0,0 +1,77 @@
+//! # Ledger Metadata Contract
+//!
+//! Demonstrates reading the ledger fields exposed through `env.ledger()`:
+//!
+//! - `sequence()` — the ledger sequence number, incremented once per ledger
+//! - `timestamp()` — the ledger close time in Unix seconds
+//! - `protocol_version()` — the network protocol version the ledger runs
+//!
+//! It also shows stamping stored data with the ledger it was written in,
+//! which is handy for ordering records or proving when something happened.
+//! In tests, `env.ledger().set_sequence_number(..)` and
+//! `env.ledger().set_timestamp(..)` simulate ledger progression.
+
+#![no_std]
+
+use soroban_sdk::{contract, contractimpl, contracttype, Env, Symbol};
+
+/// Ledger position at which a record was written.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LedgerStamp {
+    /// Ledger sequence number at write time.
+    pub sequence: u32,
+    /// Ledger close timestamp at write time.
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Record(Symbol),
+}
+
+#[contract]
+pub struct LedgerInfoContract;
+
+#[contractimpl]
+impl LedgerInfoContract {
+    /// Returns `(sequence, timestamp, protocol_version)` for the current ledger.
+    pub fn get_ledger_info(env: Env) -> (u32, u64, u32) {
+        let ledger = env.ledger();
+        (
+            ledger.sequence(),
+            ledger.timestamp(),
+            ledger.protocol_version(),
+        )
+    }
+
+    /// Stores a stamp of the current ledger under `key` and returns it.
+    ///
+    /// Calling again overwrites the stamp, so it always reflects the most
+    /// recent write.
+    pub fn record_at_ledger(env: Env, key: Symbol) -> LedgerStamp {
+        let stamp = LedgerStamp {
+            sequence: env.ledger().sequence(),
+            timestamp: env.ledger().timestamp(),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Record(key), &stamp);
+        stamp
+    }
+
+    /// Returns the stamp recorded under `key`, if any.
+    pub fn get_record(env: Env, key: Symbol) -> Option<LedgerStamp> {
+        env.storage().persistent().get(&DataKey::Record(key))
+    }
+
+    /// Number of ledgers elapsed since `key` was recorded.
+    pub fn ledgers_since(env: Env, key: Symbol) -> Option<u32> {
+        let stamp: LedgerStamp = env.storage().persistent().get(&DataKey::Record(key))?;
+        Some(env.ledger().sequence().saturating_sub(stamp.sequence))
+    }
+}
+
+#[cfg(test)]
+mod test;
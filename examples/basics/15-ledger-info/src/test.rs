@@ -0,0 +1,72 @@
+use super::*;
+use soroban_sdk::{symbol_short, testutils::Ledger, Env};
+
+fn setup() -> (Env, LedgerInfoContractClient<'static>) {
+    let env = Env::default();
+    let contract_id = env.register(LedgerInfoContract, ());
+    let client = LedgerInfoContractClient::new(&env, &contract_id);
+    (env, client)
+}
+
+#[test]
+fn test_ledger_info_tracks_simulated_ledger() {
+    let (env, client) = setup();
+
+    env.ledger().set_sequence_number(100);
+    env.ledger().set_timestamp(1_700_000_000);
+    let (sequence, timestamp, protocol) = client.get_ledger_info();
+    assert_eq!(sequence, 100);
+    assert_eq!(timestamp, 1_700_000_000);
+    assert_eq!(protocol, env.ledger().protocol_version());
+
+    env.ledger().set_sequence_number(101);
+    env.ledger().set_timestamp(1_700_000_005);
+    let (sequence, timestamp, _) = client.get_ledger_info();
+    assert_eq!(sequence, 101);
+    assert_eq!(timestamp, 1_700_000_005);
+}
+
+#[test]
+fn test_record_at_ledger_stamps_current_sequence() {
+    let (env, client) = setup();
+    let key = symbol_short!("deploy");
+
+    env.ledger().set_sequence_number(42);
+    env.ledger().set_timestamp(1_000);
+    let stamp = client.record_at_ledger(&key);
+
+    assert_eq!(
+        stamp,
+        LedgerStamp {
+            sequence: 42,
+            timestamp: 1_000
+        }
+    );
+    assert_eq!(client.get_record(&key), Some(stamp));
+}
+
+#[test]
+fn test_record_is_overwritten_on_later_ledger() {
+    let (env, client) = setup();
+    let key = symbol_short!("ping");
+
+    env.ledger().set_sequence_number(10);
+    client.record_at_ledger(&key);
+    env.ledger().set_sequence_number(25);
+    client.record_at_ledger(&key);
+
+    assert_eq!(client.get_record(&key).unwrap().sequence, 25);
+}
+
+#[test]
+fn test_ledgers_since_record() {
+    let (env, client) = setup();
+    let key = symbol_short!("mark");
+
+    assert_eq!(client.ledgers_since(&key), None);
+
+    env.ledger().set_sequence_number(50);
+    client.record_at_ledger(&key);
+    env.ledger().set_sequence_number(58);
+    assert_eq!(client.ledgers_since(&key), Some(8));
+}
@@ -0,0 +1,239 @@
+//! # Commit-Reveal Voting Contract
+//!
+//! Demonstrates private voting that resists front-running on Soroban.
+//!
+//! ## Features
+//!
+//! - **Commit**: During the commit phase a voter stores only `sha256(choice || salt)`.
+//! - **Reveal**: During the reveal phase the voter discloses `choice` and `salt`;
+//!   the contract recomputes the hash and tallies the vote only if it matches.
+//! - **Tally**: Anyone can read the per-choice counts of valid reveals.
+//!
+//! ## Design Patterns
+//!
+//! - Phases gated by ledger timestamps fixed at initialization
+//! - Hash commitments via `env.crypto().sha256`
+//! - One commitment and one reveal per address
+
+#![no_std]
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Bytes, BytesN, Env,
+};
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// Election parameters fixed at initialization.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Schedule {
+    /// Commits are accepted while `timestamp < commit_end`.
+    pub commit_end: u64,
+    /// Reveals are accepted while `commit_end <= timestamp < reveal_end`.
+    pub reveal_end: u64,
+    /// Valid choices are `0..num_choices`.
+    pub num_choices: u32,
+}
+
+// ---------------------------------------------------------------------------
+// Storage Keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Schedule,
+    Commitment(Address),
+    Revealed(Address),
+    Tally(u32),
+}
+
+// ---------------------------------------------------------------------------
+// Errors
+// ---------------------------------------------------------------------------
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum CommitRevealError {
+    /// Contract already initialized.
+    AlreadyInit = 1,
+    /// Contract not initialized.
+    NotInit = 2,
+    /// Phase boundaries or choice count are invalid.
+    BadSchedule = 3,
+    /// The commit phase has ended.
+    CommitClosed = 4,
+    /// Voter already submitted a commitment.
+    AlreadyCommitted = 5,
+    /// Current time is outside the reveal window.
+    RevealClosed = 6,
+    /// Voter never committed.
+    NoCommitment = 7,
+    /// Revealed choice and salt do not hash to the commitment.
+    Mismatch = 8,
+    /// Voter already revealed.
+    AlreadyRevealed = 9,
+    /// Choice is outside `0..num_choices`.
+    InvalidChoice = 10,
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+#[contract]
+pub struct CommitRevealVoting;
+
+#[contractimpl]
+impl CommitRevealVoting {
+    // ==================== INITIALIZATION ====================
+
+    /// Configure the commit and reveal windows and the number of choices.
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        commit_end: u64,
+        reveal_end: u64,
+        num_choices: u32,
+    ) -> Result<(), CommitRevealError> {
+        if env.storage().instance().has(&DataKey::Schedule) {
+            return Err(CommitRevealError::AlreadyInit);
+        }
+        admin.require_auth();
+
+        if commit_end <= env.ledger().timestamp() || reveal_end <= commit_end || num_choices == 0 {
+            return Err(CommitRevealError::BadSchedule);
+        }
+
+        let schedule = Schedule {
+            commit_end,
+            reveal_end,
+            num_choices,
+        };
+        env.storage().instance().set(&DataKey::Schedule, &schedule);
+
+        Ok(())
+    }
+
+    /// Returns the configured schedule.
+    pub fn get_schedule(env: Env) -> Option<Schedule> {
+        env.storage().instance().get(&DataKey::Schedule)
+    }
+
+    // ==================== COMMIT PHASE ====================
+
+    /// Store `sha256(choice || salt)` for `voter`. The choice stays hidden
+    /// until the reveal phase, so nobody can react to it.
+    pub fn commit(
+        env: Env,
+        voter: Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), CommitRevealError> {
+        voter.require_auth();
+        let schedule = Self::schedule(&env)?;
+
+        if env.ledger().timestamp() >= schedule.commit_end {
+            return Err(CommitRevealError::CommitClosed);
+        }
+
+        let key = DataKey::Commitment(voter.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(CommitRevealError::AlreadyCommitted);
+        }
+        env.storage().persistent().set(&key, &commitment);
+
+        env.events()
+            .publish((symbol_short!("commit"), voter), commitment);
+
+        Ok(())
+    }
+
+    // ==================== REVEAL PHASE ====================
+
+    /// Disclose `choice` and `salt`. The vote is tallied only if they hash
+    /// to the stored commitment.
+    pub fn reveal(
+        env: Env,
+        voter: Address,
+        choice: u32,
+        salt: BytesN<32>,
+    ) -> Result<(), CommitRevealError> {
+        voter.require_auth();
+        let schedule = Self::schedule(&env)?;
+
+        let now = env.ledger().timestamp();
+        if now < schedule.commit_end || now >= schedule.reveal_end {
+            return Err(CommitRevealError::RevealClosed);
+        }
+
+        let revealed_key = DataKey::Revealed(voter.clone());
+        if env.storage().persistent().has(&revealed_key) {
+            return Err(CommitRevealError::AlreadyRevealed);
+        }
+
+        let commitment: BytesN<32> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Commitment(voter.clone()))
+            .ok_or(CommitRevealError::NoCommitment)?;
+
+        if Self::compute_commitment(env.clone(), choice, salt) != commitment {
+            return Err(CommitRevealError::Mismatch);
+        }
+        if choice >= schedule.num_choices {
+            return Err(CommitRevealError::InvalidChoice);
+        }
+
+        let tally_key = DataKey::Tally(choice);
+        let count: u32 = env.storage().instance().get(&tally_key).unwrap_or(0);
+        env.storage().instance().set(&tally_key, &(count + 1));
+        env.storage().persistent().set(&revealed_key, &choice);
+
+        env.events()
+            .publish((symbol_short!("reveal"), voter), choice);
+
+        Ok(())
+    }
+
+    // ==================== VIEWS ====================
+
+    /// Number of valid reveals for `choice`.
+    pub fn tally(env: Env, choice: u32) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Tally(choice))
+            .unwrap_or(0)
+    }
+
+    /// The choice `voter` revealed, if any.
+    pub fn get_reveal(env: Env, voter: Address) -> Option<u32> {
+        env.storage().persistent().get(&DataKey::Revealed(voter))
+    }
+
+    /// `sha256(choice_be_bytes || salt)`. Exposed so clients can build a
+    /// commitment the same way the contract checks it.
+    pub fn compute_commitment(env: Env, choice: u32, salt: BytesN<32>) -> BytesN<32> {
+        let mut preimage = Bytes::from_array(&env, &choice.to_be_bytes());
+        preimage.append(&Bytes::from(salt));
+        env.crypto().sha256(&preimage).into()
+    }
+
+    // ==================== HELPERS ====================
+
+    fn schedule(env: &Env) -> Result<Schedule, CommitRevealError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Schedule)
+            .ok_or(CommitRevealError::NotInit)
+    }
+}
+
+// ===========================================================================
+// Tests
+// ===========================================================================
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,141 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Env,
+};
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+const COMMIT_END: u64 = 1_000;
+const REVEAL_END: u64 = 2_000;
+
+fn setup(env: &Env) -> CommitRevealVotingClient<'_> {
+    let contract_id = env.register(CommitRevealVoting, ());
+    let client = CommitRevealVotingClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    env.mock_all_auths();
+    env.ledger().set_timestamp(100);
+    client.initialize(&admin, &COMMIT_END, &REVEAL_END, &3);
+    client
+}
+
+fn salt(env: &Env, seed: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[seed; 32])
+}
+
+// ---------------------------------------------------------------------------
+// Initialization
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_initialize_rejects_bad_schedule() {
+    let env = Env::default();
+    let contract_id = env.register(CommitRevealVoting, ());
+    let client = CommitRevealVotingClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    env.mock_all_auths();
+
+    let result = client.try_initialize(&admin, &500, &500, &2);
+    assert_eq!(result, Err(Ok(CommitRevealError::BadSchedule)));
+}
+
+// ---------------------------------------------------------------------------
+// Commit / reveal
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_valid_commit_reveal_is_tallied() {
+    let env = Env::default();
+    let client = setup(&env);
+    let voter = Address::generate(&env);
+
+    let commitment = client.compute_commitment(&2, &salt(&env, 7));
+    client.commit(&voter, &commitment);
+
+    env.ledger().set_timestamp(COMMIT_END);
+    client.reveal(&voter, &2, &salt(&env, 7));
+
+    assert_eq!(client.tally(&2), 1);
+    assert_eq!(client.tally(&0), 0);
+    assert_eq!(client.get_reveal(&voter), Some(2));
+}
+
+#[test]
+fn test_commitment_matches_manual_hash() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    let mut preimage = Bytes::from_array(&env, &1u32.to_be_bytes());
+    preimage.append(&Bytes::from(salt(&env, 9)));
+    let expected: BytesN<32> = env.crypto().sha256(&preimage).into();
+
+    assert_eq!(client.compute_commitment(&1, &salt(&env, 9)), expected);
+}
+
+#[test]
+fn test_mismatched_reveal_rejected() {
+    let env = Env::default();
+    let client = setup(&env);
+    let voter = Address::generate(&env);
+
+    client.commit(&voter, &client.compute_commitment(&1, &salt(&env, 1)));
+    env.ledger().set_timestamp(COMMIT_END + 1);
+
+    // Wrong choice
+    let result = client.try_reveal(&voter, &0, &salt(&env, 1));
+    assert_eq!(result, Err(Ok(CommitRevealError::Mismatch)));
+
+    // Wrong salt
+    let result = client.try_reveal(&voter, &1, &salt(&env, 2));
+    assert_eq!(result, Err(Ok(CommitRevealError::Mismatch)));
+
+    assert_eq!(client.tally(&1), 0);
+}
+
+#[test]
+fn test_reveal_outside_window_rejected() {
+    let env = Env::default();
+    let client = setup(&env);
+    let voter = Address::generate(&env);
+
+    client.commit(&voter, &client.compute_commitment(&0, &salt(&env, 3)));
+
+    // Still in the commit phase
+    let result = client.try_reveal(&voter, &0, &salt(&env, 3));
+    assert_eq!(result, Err(Ok(CommitRevealError::RevealClosed)));
+
+    // Reveal phase over
+    env.ledger().set_timestamp(REVEAL_END);
+    let result = client.try_reveal(&voter, &0, &salt(&env, 3));
+    assert_eq!(result, Err(Ok(CommitRevealError::RevealClosed)));
+
+    assert_eq!(client.tally(&0), 0);
+}
+
+#[test]
+fn test_commit_after_commit_phase_rejected() {
+    let env = Env::default();
+    let client = setup(&env);
+    let voter = Address::generate(&env);
+
+    env.ledger().set_timestamp(COMMIT_END);
+    let result = client.try_commit(&voter, &client.compute_commitment(&0, &salt(&env, 4)));
+    assert_eq!(result, Err(Ok(CommitRevealError::CommitClosed)));
+}
+
+#[test]
+fn test_double_reveal_rejected() {
+    let env = Env::default();
+    let client = setup(&env);
+    let voter = Address::generate(&env);
+
+    client.commit(&voter, &client.compute_commitment(&1, &salt(&env, 5)));
+    env.ledger().set_timestamp(COMMIT_END);
+    client.reveal(&voter, &1, &salt(&env, 5));
+
+    let result = client.try_reveal(&voter, &1, &salt(&env, 5));
+    assert_eq!(result, Err(Ok(CommitRevealError::AlreadyRevealed)));
+    assert_eq!(client.tally(&1), 1);
+}
@@ -0,0 +1,215 @@
+//! # Rate-Limited Faucet
+//!
+//! Builds on the per-address cooldown from `03-authentication`
+//! (`DataKey::LastAction`) to hand out a fixed drip no more than once per
+//! cooldown window per address.
+//!
+//! ## Operational Guidance
+//!
+//! - The admin configures the drip amount and cooldown, and refills the
+//!   reserve the faucet pays out from.
+//! - `LastClaim(Address)` records the timestamp of each address's most
+//!   recent successful claim; a claim is allowed once
+//!   `now >= last_claim + cooldown`.
+//! - Balances are tracked inside the contract to keep the example
+//!   self-contained; a real faucet would transfer a token instead.
+
+#![no_std]
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Env, Symbol,
+};
+
+// ---------------------------------------------------------------------------
+// Errors
+// ---------------------------------------------------------------------------
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum FaucetError {
+    /// Contract has already been initialized.
+    AlreadyInitialized = 1,
+    /// Contract has not been initialized yet.
+    NotInitialized = 2,
+    /// Caller is not the admin.
+    NotAuthorized = 3,
+    /// The caller claimed too recently.
+    CooldownActive = 4,
+    /// The reserve cannot cover the drip amount.
+    FaucetEmpty = 5,
+    /// Amount must be positive.
+    InvalidAmount = 6,
+}
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,
+    DripAmount,
+    Cooldown,
+    Reserve,
+    LastClaim(Address),
+    Balance(Address),
+}
+
+// ---------------------------------------------------------------------------
+// Events
+// ---------------------------------------------------------------------------
+
+const CONTRACT_NS: Symbol = symbol_short!("faucet");
+const ACTION_CLAIM: Symbol = symbol_short!("claim");
+const ACTION_REFILL: Symbol = symbol_short!("refill");
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+#[contract]
+pub struct FaucetContract;
+
+#[contractimpl]
+impl FaucetContract {
+    /// Set the admin, drip amount and cooldown (in seconds).
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        drip_amount: i128,
+        cooldown: u64,
+    ) -> Result<(), FaucetError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(FaucetError::AlreadyInitialized);
+        }
+        if drip_amount <= 0 {
+            return Err(FaucetError::InvalidAmount);
+        }
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::DripAmount, &drip_amount);
+        env.storage().instance().set(&DataKey::Cooldown, &cooldown);
+        env.storage().instance().set(&DataKey::Reserve, &0i128);
+        Ok(())
+    }
+
+    /// Dispense the drip amount to `user` if their cooldown has elapsed.
+    pub fn claim(env: Env, user: Address) -> Result<i128, FaucetError> {
+        user.require_auth();
+
+        let drip: i128 = Self::get_drip_amount(env.clone())?;
+        let cooldown: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::Cooldown)
+            .unwrap_or(0);
+        let now = env.ledger().timestamp();
+
+        let last_key = DataKey::LastClaim(user.clone());
+        if let Some(last_claim) = env.storage().persistent().get::<_, u64>(&last_key) {
+            if now < last_claim.saturating_add(cooldown) {
+                return Err(FaucetError::CooldownActive);
+            }
+        }
+
+        let reserve = Self::reserve(env.clone());
+        if reserve < drip {
+            return Err(FaucetError::FaucetEmpty);
+        }
+
+        let balance = Self::balance(env.clone(), user.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::Reserve, &(reserve - drip));
+        env.storage()
+            .persistent()
+            .set(&DataKey::Balance(user.clone()), &(balance + drip));
+        env.storage().persistent().set(&last_key, &now);
+
+        env.events()
+            .publish((CONTRACT_NS, ACTION_CLAIM, user), drip);
+
+        Ok(drip)
+    }
+
+    /// Change the drip amount and cooldown (admin-only).
+    pub fn configure(env: Env, drip_amount: i128, cooldown: u64) -> Result<(), FaucetError> {
+        Self::require_admin(&env)?;
+        if drip_amount <= 0 {
+            return Err(FaucetError::InvalidAmount);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::DripAmount, &drip_amount);
+        env.storage().instance().set(&DataKey::Cooldown, &cooldown);
+        Ok(())
+    }
+
+    /// Add `amount` to the reserve (admin-only).
+    pub fn refill(env: Env, amount: i128) -> Result<(), FaucetError> {
+        let admin = Self::require_admin(&env)?;
+        if amount <= 0 {
+            return Err(FaucetError::InvalidAmount);
+        }
+        let reserve = Self::reserve(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::Reserve, &(reserve + amount));
+
+        env.events()
+            .publish((CONTRACT_NS, ACTION_REFILL, admin), amount);
+        Ok(())
+    }
+
+    // ── queries ─────────────────────────────────────────────────────────────
+
+    pub fn get_drip_amount(env: Env) -> Result<i128, FaucetError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::DripAmount)
+            .ok_or(FaucetError::NotInitialized)
+    }
+
+    pub fn get_cooldown(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Cooldown)
+            .unwrap_or(0)
+    }
+
+    pub fn reserve(env: Env) -> i128 {
+        env.storage().instance().get(&DataKey::Reserve).unwrap_or(0)
+    }
+
+    pub fn balance(env: Env, user: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Balance(user))
+            .unwrap_or(0)
+    }
+
+    /// Timestamp of `user`'s last successful claim, if any.
+    pub fn last_claim(env: Env, user: Address) -> Option<u64> {
+        env.storage().persistent().get(&DataKey::LastClaim(user))
+    }
+
+    // ── internal ────────────────────────────────────────────────────────────
+
+    fn require_admin(env: &Env) -> Result<Address, FaucetError> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(FaucetError::NotInitialized)?;
+        admin.require_auth();
+        Ok(admin)
+    }
+}
+
+#[cfg(test)]
+mod test;
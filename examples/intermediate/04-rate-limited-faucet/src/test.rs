@@ -0,0 +1,115 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+const DRIP: i128 = 100;
+const COOLDOWN: u64 = 3_600;
+
+fn setup() -> (Env, Address, FaucetContractClient<'static>) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let contract_id = env.register(FaucetContract, ());
+    let client = FaucetContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &DRIP, &COOLDOWN);
+    client.refill(&1_000);
+    (env, admin, client)
+}
+
+// ── initialization ──────────────────────────────────────────────────────────
+
+#[test]
+fn test_initialize_twice_fails() {
+    let (_env, admin, client) = setup();
+    let result = client.try_initialize(&admin, &DRIP, &COOLDOWN);
+    assert_eq!(result, Err(Ok(FaucetError::AlreadyInitialized)));
+}
+
+// ── claiming ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_first_claim_succeeds() {
+    let (env, _admin, client) = setup();
+    let user = Address::generate(&env);
+
+    assert_eq!(client.claim(&user), DRIP);
+    assert_eq!(client.balance(&user), DRIP);
+    assert_eq!(client.reserve(), 900);
+    assert_eq!(client.last_claim(&user), Some(1_000));
+}
+
+#[test]
+fn test_immediate_second_claim_rejected() {
+    let (env, _admin, client) = setup();
+    let user = Address::generate(&env);
+
+    client.claim(&user);
+    let result = client.try_claim(&user);
+    assert_eq!(result, Err(Ok(FaucetError::CooldownActive)));
+
+    // One second short of the cooldown is still rejected
+    env.ledger().set_timestamp(1_000 + COOLDOWN - 1);
+    let result = client.try_claim(&user);
+    assert_eq!(result, Err(Ok(FaucetError::CooldownActive)));
+    assert_eq!(client.balance(&user), DRIP);
+}
+
+#[test]
+fn test_claim_after_cooldown_succeeds() {
+    let (env, _admin, client) = setup();
+    let user = Address::generate(&env);
+
+    client.claim(&user);
+    env.ledger().set_timestamp(1_000 + COOLDOWN);
+    client.claim(&user);
+
+    assert_eq!(client.balance(&user), DRIP * 2);
+    assert_eq!(client.last_claim(&user), Some(1_000 + COOLDOWN));
+}
+
+#[test]
+fn test_cooldown_is_per_address() {
+    let (env, _admin, client) = setup();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    client.claim(&alice);
+    client.claim(&bob);
+    assert_eq!(client.balance(&bob), DRIP);
+}
+
+#[test]
+fn test_empty_faucet_rejects_claim() {
+    let (env, _admin, client) = setup();
+    client.configure(&600, &COOLDOWN);
+
+    client.claim(&Address::generate(&env));
+    let result = client.try_claim(&Address::generate(&env));
+    assert_eq!(result, Err(Ok(FaucetError::FaucetEmpty)));
+}
+
+// ── admin configuration ─────────────────────────────────────────────────────
+
+#[test]
+fn test_configure_updates_drip_and_cooldown() {
+    let (env, _admin, client) = setup();
+    client.configure(&250, &60);
+    assert_eq!(client.get_drip_amount(), 250);
+    assert_eq!(client.get_cooldown(), 60);
+
+    let user = Address::generate(&env);
+    client.claim(&user);
+    env.ledger().set_timestamp(1_060);
+    client.claim(&user);
+    assert_eq!(client.balance(&user), 500);
+}
+
+#[test]
+fn test_refill_rejects_non_positive_amount() {
+    let (_env, _admin, client) = setup();
+    let result = client.try_refill(&0);
+    assert_eq!(result, Err(Ok(FaucetError::InvalidAmount)));
+}
@@ -0,0 +1,16 @@
+//! Minimal child contract deployed by the factory example.
+
+#![no_std]
+
+use soroban_sdk::{contract, contractimpl, Env};
+
+#[contract]
+pub struct ChildContract;
+
+#[contractimpl]
+impl ChildContract {
+    /// Returns a fixed value so tests can confirm a deployed child is live.
+    pub fn ping(_env: Env) -> u32 {
+        1
+    }
+}
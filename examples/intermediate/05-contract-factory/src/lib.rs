@@ -0,0 +1,105 @@
+//! # Contract Factory
+//!
+//! Demonstrates one contract deploying others with `env.deployer()`.
+//!
+//! ## How deployment works
+//!
+//! 1. The child contract's Wasm is uploaded once, producing a `wasm_hash`.
+//! 2. The factory calls
+//!    `env.deployer().with_address(deployer, salt).deploy_v2(wasm_hash, ())`.
+//!    The new contract's address is derived from `(deployer, salt)`, so it is
+//!    deterministic and can be computed before deployment with
+//!    `deployed_address()`.
+//! 3. The factory records every child address in a `Vec<Address>` registry.
+//!
+//! Using the factory's own address as the deployer means no extra
+//! authorization is needed for the deployment itself; the admin's
+//! `require_auth` gates who may trigger it.
+
+#![no_std]
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short, Address, BytesN, Env, Vec,
+};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum FactoryError {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Admin,
+    Children,
+}
+
+#[contract]
+pub struct ContractFactory;
+
+#[contractimpl]
+impl ContractFactory {
+    /// Set the admin allowed to deploy children.
+    pub fn initialize(env: Env, admin: Address) -> Result<(), FactoryError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(FactoryError::AlreadyInitialized);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::Children, &Vec::<Address>::new(&env));
+        Ok(())
+    }
+
+    /// Deploy a new child from the uploaded `wasm_hash` and register it.
+    ///
+    /// Each `salt` can be used once: deploying twice with the same salt
+    /// targets the same address and is rejected by the host.
+    pub fn deploy_child(
+        env: Env,
+        salt: BytesN<32>,
+        wasm_hash: BytesN<32>,
+    ) -> Result<Address, FactoryError> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(FactoryError::NotInitialized)?;
+        admin.require_auth();
+
+        let child = env
+            .deployer()
+            .with_address(env.current_contract_address(), salt)
+            .deploy_v2(wasm_hash, ());
+
+        let mut children = Self::get_children(env.clone());
+        children.push_back(child.clone());
+        env.storage().instance().set(&DataKey::Children, &children);
+
+        env.events()
+            .publish((symbol_short!("deployed"), child.clone()), admin);
+
+        Ok(child)
+    }
+
+    /// Address a child deployed with `salt` will have (or already has).
+    pub fn child_address(env: Env, salt: BytesN<32>) -> Address {
+        env.deployer()
+            .with_address(env.current_contract_address(), salt)
+            .deployed_address()
+    }
+
+    /// All children deployed by this factory, in deployment order.
+    pub fn get_children(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Children)
+            .unwrap_or(Vec::new(&env))
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,73 @@
+use super::*;
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+// Build the child first, from the repository root:
+//   ./scripts/build-test-wasm.sh
+mod child {
+    soroban_sdk::contractimport!(file = "../../../target/wasm32v1-none/release/factory_child.wasm");
+}
+
+fn setup() -> (Env, ContractFactoryClient<'static>, BytesN<32>) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let wasm_hash = env.deployer().upload_contract_wasm(child::WASM);
+
+    let factory_id = env.register(ContractFactory, ());
+    let factory = ContractFactoryClient::new(&env, &factory_id);
+    factory.initialize(&Address::generate(&env));
+
+    (env, factory, wasm_hash)
+}
+
+#[test]
+fn test_deploy_two_children_with_distinct_salts() {
+    let (env, factory, wasm_hash) = setup();
+
+    let salt_a = BytesN::from_array(&env, &[1; 32]);
+    let salt_b = BytesN::from_array(&env, &[2; 32]);
+
+    let child_a = factory.deploy_child(&salt_a, &wasm_hash);
+    let child_b = factory.deploy_child(&salt_b, &wasm_hash);
+    assert_ne!(child_a, child_b);
+
+    let children = factory.get_children();
+    assert_eq!(children.len(), 2);
+    assert_eq!(children.get(0).unwrap(), child_a);
+    assert_eq!(children.get(1).unwrap(), child_b);
+
+    // Both children are live contracts running the uploaded code
+    assert_eq!(child::Client::new(&env, &child_a).ping(), 1);
+    assert_eq!(child::Client::new(&env, &child_b).ping(), 1);
+}
+
+#[test]
+fn test_child_address_is_predictable() {
+    let (env, factory, wasm_hash) = setup();
+    let salt = BytesN::from_array(&env, &[7; 32]);
+
+    let predicted = factory.child_address(&salt);
+    let deployed = factory.deploy_child(&salt, &wasm_hash);
+    assert_eq!(predicted, deployed);
+}
+
+#[test]
+fn test_reusing_salt_fails() {
+    let (env, factory, wasm_hash) = setup();
+    let salt = BytesN::from_array(&env, &[3; 32]);
+
+    factory.deploy_child(&salt, &wasm_hash);
+    assert!(factory.try_deploy_child(&salt, &wasm_hash).is_err());
+    assert_eq!(factory.get_children().len(), 1);
+}
+
+#[test]
+fn test_deploy_before_initialize_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let wasm_hash = env.deployer().upload_contract_wasm(child::WASM);
+    let factory = ContractFactoryClient::new(&env, &env.register(ContractFactory, ()));
+
+    let result = factory.try_deploy_child(&BytesN::from_array(&env, &[0; 32]), &wasm_hash);
+    assert_eq!(result, Err(Ok(FactoryError::NotInitialized)));
+}
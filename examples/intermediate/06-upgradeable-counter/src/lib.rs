@@ -0,0 +1,104 @@
+//! # Upgradeable Counter
+//!
+//! Shows that contract data survives a Wasm upgrade.
+//!
+//! `upgrade` calls `env.deployer().update_current_contract_wasm(new_wasm_hash)`,
+//! which swaps the code behind the contract's address while keeping its
+//! storage. The next invocation runs the new code against the old data, so
+//! the new version must read the same `DataKey` layout.
+//!
+//! The `v2/` crate is that new version: the same storage and functions plus
+//! `decrement`.
+
+#![no_std]
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short, Address, BytesN, Env,
+};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CounterError {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    /// The `admin` passed to `upgrade` is not the stored admin.
+    Unauthorized = 3,
+}
+
+/// Storage layout shared by every version of the counter.
+///
+/// * `Admin` -- address allowed to upgrade (instance storage).
+/// * `Count` -- the counter value (persistent storage).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Admin,
+    Count,
+}
+
+#[contract]
+pub struct UpgradeableCounter;
+
+#[contractimpl]
+impl UpgradeableCounter {
+    /// Set the admin allowed to upgrade the contract.
+    pub fn initialize(env: Env, admin: Address) -> Result<(), CounterError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(CounterError::AlreadyInitialized);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        Ok(())
+    }
+
+    /// Add one to the counter and return the new value.
+    pub fn increment(env: Env) -> u32 {
+        let count = Self::get(env.clone()) + 1;
+        env.storage().persistent().set(&DataKey::Count, &count);
+        env.storage()
+            .persistent()
+            .extend_ttl(&DataKey::Count, 1000, 10000);
+        count
+    }
+
+    /// Current counter value (0 before the first increment).
+    pub fn get(env: Env) -> u32 {
+        env.storage().persistent().get(&DataKey::Count).unwrap_or(0)
+    }
+
+    /// Code version, so callers can tell which Wasm is live.
+    pub fn version(_env: Env) -> u32 {
+        1
+    }
+
+    /// Replace this contract's code with `new_wasm_hash` (admin-only).
+    ///
+    /// The caller names the `admin` explicitly; it must match the stored
+    /// admin and sign the call. Storage is untouched; only the executable
+    /// changes.
+    pub fn upgrade(
+        env: Env,
+        admin: Address,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<(), CounterError> {
+        let stored: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(CounterError::NotInitialized)?;
+        if admin != stored {
+            return Err(CounterError::Unauthorized);
+        }
+        admin.require_auth();
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        env.events()
+            .publish((symbol_short!("upgrade"), admin), new_wasm_hash);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,118 @@
+use super::*;
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+// Build v2 first, from the repository root:
+//   ./scripts/build-test-wasm.sh
+mod v2 {
+    soroban_sdk::contractimport!(
+        file = "../../../target/wasm32v1-none/release/upgradeable_counter_v2.wasm"
+    );
+}
+
+fn setup() -> (Env, Address, UpgradeableCounterClient<'static>) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(UpgradeableCounter, ());
+    let client = UpgradeableCounterClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    (env, admin, client)
+}
+
+#[test]
+fn test_increment_and_get() {
+    let (_env, _admin, client) = setup();
+
+    assert_eq!(client.get(), 0);
+    assert_eq!(client.increment(), 1);
+    assert_eq!(client.increment(), 2);
+    assert_eq!(client.get(), 2);
+    assert_eq!(client.version(), 1);
+}
+
+#[test]
+fn test_count_survives_upgrade() {
+    let (env, admin, client) = setup();
+    for _ in 0..3 {
+        client.increment();
+    }
+
+    let v2_hash = env.deployer().upload_contract_wasm(v2::WASM);
+    client.upgrade(&admin, &v2_hash);
+
+    // Same address, new code
+    let upgraded = v2::Client::new(&env, &client.address);
+    assert_eq!(upgraded.version(), 2);
+    assert_eq!(upgraded.get(), 3);
+
+    // The new function works on the old data
+    assert_eq!(upgraded.decrement(), 2);
+    assert_eq!(upgraded.increment(), 3);
+    assert_eq!(upgraded.get(), 3);
+}
+
+#[test]
+fn test_v2_decrement_underflow() {
+    let (env, admin, client) = setup();
+
+    let v2_hash = env.deployer().upload_contract_wasm(v2::WASM);
+    client.upgrade(&admin, &v2_hash);
+
+    let upgraded = v2::Client::new(&env, &client.address);
+    assert_eq!(
+        upgraded.try_decrement(),
+        Err(Ok(v2::CounterError::Underflow))
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError")]
+fn test_upgrade_requires_admin_auth() {
+    let env = Env::default();
+    let contract_id = env.register(UpgradeableCounter, ());
+    let client = UpgradeableCounterClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let v2_hash = env.deployer().upload_contract_wasm(v2::WASM);
+    // No mocked auths: the admin has not signed
+    client.upgrade(&admin, &v2_hash);
+}
+
+#[test]
+fn test_upgrade_rejects_non_admin() {
+    let (env, _admin, client) = setup();
+    let other = Address::generate(&env);
+
+    let v2_hash = env.deployer().upload_contract_wasm(v2::WASM);
+    assert_eq!(
+        client.try_upgrade(&other, &v2_hash),
+        Err(Ok(CounterError::Unauthorized))
+    );
+    assert_eq!(client.version(), 1);
+}
+
+#[test]
+fn test_upgrade_before_initialize_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(UpgradeableCounter, ());
+    let client = UpgradeableCounterClient::new(&env, &contract_id);
+
+    let v2_hash = env.deployer().upload_contract_wasm(v2::WASM);
+    assert_eq!(
+        client.try_upgrade(&Address::generate(&env), &v2_hash),
+        Err(Ok(CounterError::NotInitialized))
+    );
+}
+
+#[test]
+fn test_initialize_twice_fails() {
+    let (env, _admin, client) = setup();
+    assert_eq!(
+        client.try_initialize(&Address::generate(&env)),
+        Err(Ok(CounterError::AlreadyInitialized))
+    );
+}
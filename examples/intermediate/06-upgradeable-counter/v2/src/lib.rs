@@ -0,0 +1,98 @@
+//! Version 2 of the upgradeable counter, installed by the example's tests.
+//!
+//! Keeps the v1 storage layout and functions and adds `decrement`.
+
+#![no_std]
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short, Address, BytesN, Env,
+};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CounterError {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    /// The `admin` passed to `upgrade` is not the stored admin.
+    Unauthorized = 3,
+    /// `decrement` was called while the counter is 0.
+    Underflow = 4,
+}
+
+/// Must match v1 exactly, or the upgraded code cannot read existing data.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Admin,
+    Count,
+}
+
+#[contract]
+pub struct UpgradeableCounter;
+
+#[contractimpl]
+impl UpgradeableCounter {
+    pub fn initialize(env: Env, admin: Address) -> Result<(), CounterError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(CounterError::AlreadyInitialized);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        Ok(())
+    }
+
+    pub fn increment(env: Env) -> u32 {
+        let count = Self::get(env.clone()) + 1;
+        Self::store(&env, count);
+        count
+    }
+
+    /// New in v2: subtract one from the counter and return the new value.
+    pub fn decrement(env: Env) -> Result<u32, CounterError> {
+        let count = Self::get(env.clone())
+            .checked_sub(1)
+            .ok_or(CounterError::Underflow)?;
+        Self::store(&env, count);
+        Ok(count)
+    }
+
+    pub fn get(env: Env) -> u32 {
+        env.storage().persistent().get(&DataKey::Count).unwrap_or(0)
+    }
+
+    pub fn version(_env: Env) -> u32 {
+        2
+    }
+
+    pub fn upgrade(
+        env: Env,
+        admin: Address,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<(), CounterError> {
+        let stored: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(CounterError::NotInitialized)?;
+        if admin != stored {
+            return Err(CounterError::Unauthorized);
+        }
+        admin.require_auth();
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        env.events()
+            .publish((symbol_short!("upgrade"), admin), new_wasm_hash);
+        Ok(())
+    }
+}
+
+impl UpgradeableCounter {
+    fn store(env: &Env, count: u32) {
+        env.storage().persistent().set(&DataKey::Count, &count);
+        env.storage()
+            .persistent()
+            .extend_ttl(&DataKey::Count, 1000, 10000);
+    }
+}
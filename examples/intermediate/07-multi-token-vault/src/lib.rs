@@ -0,0 +1,137 @@
+//! # Multi-Token Vault
+//!
+//! Builds on the single-balance pattern from `03-authentication`
+//! (`DataKey::Balance(Address)`) by keying the ledger on
+//! `(token, user)` so one contract can custody any number of assets.
+//!
+//! ## Operational Guidance
+//!
+//! - `deposit` pulls tokens from the depositor with the token contract's
+//!   `transfer`, so the depositor must authorize both the vault call and the
+//!   nested token transfer.
+//! - `withdraw` checks the internal ledger first and only then pays out from
+//!   the vault's own token balance.
+//! - Balances for different tokens are independent: a deposit of one asset
+//!   never allows withdrawing another.
+
+#![no_std]
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short, token, Address, Env, Symbol,
+};
+
+// ---------------------------------------------------------------------------
+// Errors
+// ---------------------------------------------------------------------------
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum VaultError {
+    /// Amount must be positive.
+    InvalidAmount = 1,
+    /// The caller's vault balance for this token is too low.
+    InsufficientBalance = 2,
+}
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    /// Vault balance of `user` in `token` (persistent storage).
+    Balance(Address, Address),
+}
+
+// ---------------------------------------------------------------------------
+// Events
+// ---------------------------------------------------------------------------
+
+const CONTRACT_NS: Symbol = symbol_short!("vault");
+const ACTION_DEPOSIT: Symbol = symbol_short!("deposit");
+const ACTION_WITHDRAW: Symbol = symbol_short!("withdraw");
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+#[contract]
+pub struct VaultContract;
+
+#[contractimpl]
+impl VaultContract {
+    /// Move `amount` of `token` from `from` into the vault and credit it.
+    pub fn deposit(
+        env: Env,
+        token: Address,
+        from: Address,
+        amount: i128,
+    ) -> Result<(), VaultError> {
+        from.require_auth();
+        if amount <= 0 {
+            return Err(VaultError::InvalidAmount);
+        }
+
+        token::TokenClient::new(&env, &token).transfer(
+            &from,
+            env.current_contract_address(),
+            &amount,
+        );
+
+        let balance = Self::balance(env.clone(), token.clone(), from.clone());
+        Self::set_balance(&env, &token, &from, balance + amount);
+
+        env.events()
+            .publish((CONTRACT_NS, ACTION_DEPOSIT, token, from), amount);
+        Ok(())
+    }
+
+    /// Debit `amount` of `token` from `to`'s vault balance and pay it out.
+    pub fn withdraw(env: Env, token: Address, to: Address, amount: i128) -> Result<(), VaultError> {
+        to.require_auth();
+        if amount <= 0 {
+            return Err(VaultError::InvalidAmount);
+        }
+
+        let balance = Self::balance(env.clone(), token.clone(), to.clone());
+        if balance < amount {
+            return Err(VaultError::InsufficientBalance);
+        }
+
+        // Update the ledger before the external call
+        Self::set_balance(&env, &token, &to, balance - amount);
+        token::TokenClient::new(&env, &token).transfer(
+            &env.current_contract_address(),
+            &to,
+            &amount,
+        );
+
+        env.events()
+            .publish((CONTRACT_NS, ACTION_WITHDRAW, token, to), amount);
+        Ok(())
+    }
+
+    /// Vault balance of `user` in `token`.
+    pub fn balance(env: Env, token: Address, user: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Balance(token, user))
+            .unwrap_or(0)
+    }
+
+    // ── helpers ─────────────────────────────────────────────────────────────
+
+    fn set_balance(env: &Env, token: &Address, user: &Address, amount: i128) {
+        let key = DataKey::Balance(token.clone(), user.clone());
+        if amount == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &amount);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test;
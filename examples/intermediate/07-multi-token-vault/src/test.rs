@@ -0,0 +1,117 @@
+use super::*;
+use soroban_sdk::{
+    testutils::Address as _,
+    token::{StellarAssetClient, TokenClient},
+    Address, Env,
+};
+
+struct Setup<'a> {
+    env: Env,
+    vault: VaultContractClient<'a>,
+    vault_id: Address,
+    token_a: Address,
+    token_b: Address,
+    user: Address,
+}
+
+fn create_token(env: &Env, user: &Address, amount: i128) -> Address {
+    let issuer = Address::generate(env);
+    let token = env.register_stellar_asset_contract_v2(issuer).address();
+    StellarAssetClient::new(env, &token).mint(user, &amount);
+    token
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    let vault_id = env.register(VaultContract, ());
+    let vault = VaultContractClient::new(&env, &vault_id);
+    let user = Address::generate(&env);
+    let token_a = create_token(&env, &user, 1_000);
+    let token_b = create_token(&env, &user, 1_000);
+    Setup {
+        env,
+        vault,
+        vault_id,
+        token_a,
+        token_b,
+        user,
+    }
+}
+
+// ── deposits ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_deposit_moves_tokens_and_credits_ledger() {
+    let s = setup();
+    s.vault.deposit(&s.token_a, &s.user, &300);
+
+    assert_eq!(s.vault.balance(&s.token_a, &s.user), 300);
+    let token = TokenClient::new(&s.env, &s.token_a);
+    assert_eq!(token.balance(&s.user), 700);
+    assert_eq!(token.balance(&s.vault_id), 300);
+}
+
+#[test]
+fn test_balances_are_tracked_per_token() {
+    let s = setup();
+    s.vault.deposit(&s.token_a, &s.user, &300);
+    s.vault.deposit(&s.token_b, &s.user, &50);
+
+    assert_eq!(s.vault.balance(&s.token_a, &s.user), 300);
+    assert_eq!(s.vault.balance(&s.token_b, &s.user), 50);
+
+    s.vault.withdraw(&s.token_a, &s.user, &100);
+    assert_eq!(s.vault.balance(&s.token_a, &s.user), 200);
+    assert_eq!(s.vault.balance(&s.token_b, &s.user), 50);
+}
+
+#[test]
+fn test_deposit_rejects_non_positive_amount() {
+    let s = setup();
+    let result = s.vault.try_deposit(&s.token_a, &s.user, &0);
+    assert_eq!(result, Err(Ok(VaultError::InvalidAmount)));
+}
+
+// ── withdrawals ─────────────────────────────────────────────────────────────
+
+#[test]
+fn test_withdraw_pays_out() {
+    let s = setup();
+    s.vault.deposit(&s.token_a, &s.user, &300);
+    s.vault.withdraw(&s.token_a, &s.user, &300);
+
+    assert_eq!(s.vault.balance(&s.token_a, &s.user), 0);
+    let token = TokenClient::new(&s.env, &s.token_a);
+    assert_eq!(token.balance(&s.user), 1_000);
+    assert_eq!(token.balance(&s.vault_id), 0);
+}
+
+#[test]
+fn test_withdraw_more_than_deposited_fails() {
+    let s = setup();
+    s.vault.deposit(&s.token_a, &s.user, &100);
+    let result = s.vault.try_withdraw(&s.token_a, &s.user, &101);
+    assert_eq!(result, Err(Ok(VaultError::InsufficientBalance)));
+    assert_eq!(s.vault.balance(&s.token_a, &s.user), 100);
+}
+
+#[test]
+fn test_cannot_withdraw_other_token_balance() {
+    let s = setup();
+    // The vault holds token A for the user, but nothing in token B
+    s.vault.deposit(&s.token_a, &s.user, &500);
+    let result = s.vault.try_withdraw(&s.token_b, &s.user, &1);
+    assert_eq!(result, Err(Ok(VaultError::InsufficientBalance)));
+}
+
+#[test]
+fn test_users_are_isolated() {
+    let s = setup();
+    let other = Address::generate(&s.env);
+    s.vault.deposit(&s.token_a, &s.user, &100);
+
+    assert_eq!(s.vault.balance(&s.token_a, &other), 0);
+    let result = s.vault.try_withdraw(&s.token_a, &other, &1);
+    assert_eq!(result, Err(Ok(VaultError::InsufficientBalance)));
+}
@@ -0,0 +1,169 @@
+//! # Permission Flags
+//!
+//! Role enums (see `03-authentication`) give each address exactly one tier.
+//! This example instead stores a `u32` bitmask per address, where every bit
+//! is an independent permission, so any combination can be granted.
+//!
+//! ## Operational Guidance
+//!
+//! - Flags are single bits: [`READ`], [`WRITE`], [`DELETE`]. Several can be
+//!   combined with `|` and granted or revoked in one call.
+//! - Granting sets bits (`mask | flag`), revoking clears them
+//!   (`mask & !flag`); neither touches the other bits.
+//! - `has_permission` requires **all** bits of `flag` to be set.
+
+#![no_std]
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Env, Symbol,
+};
+
+/// May read protected data.
+pub const READ: u32 = 1 << 0;
+/// May modify protected data.
+pub const WRITE: u32 = 1 << 1;
+/// May delete protected data.
+pub const DELETE: u32 = 1 << 2;
+
+/// Every flag this contract defines.
+pub const ALL_FLAGS: u32 = READ | WRITE | DELETE;
+
+// ---------------------------------------------------------------------------
+// Errors
+// ---------------------------------------------------------------------------
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PermissionError {
+    /// Contract has already been initialized.
+    AlreadyInitialized = 1,
+    /// Contract has not been initialized yet.
+    NotInitialized = 2,
+    /// Caller is not the admin.
+    NotAuthorized = 3,
+    /// The flag is zero or contains undefined bits.
+    InvalidFlag = 4,
+}
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,
+    /// Permission bitmask of an address (persistent storage).
+    Permissions(Address),
+}
+
+// ---------------------------------------------------------------------------
+// Events
+// ---------------------------------------------------------------------------
+
+const CONTRACT_NS: Symbol = symbol_short!("perms");
+const ACTION_GRANT: Symbol = symbol_short!("grant");
+const ACTION_REVOKE: Symbol = symbol_short!("revoke");
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+#[contract]
+pub struct PermissionContract;
+
+#[contractimpl]
+impl PermissionContract {
+    pub fn initialize(env: Env, admin: Address) -> Result<(), PermissionError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(PermissionError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        Ok(())
+    }
+
+    /// Set the bits in `flag` for `user` (admin-only).
+    pub fn grant_permission(
+        env: Env,
+        admin: Address,
+        user: Address,
+        flag: u32,
+    ) -> Result<u32, PermissionError> {
+        Self::require_admin(&env, &admin)?;
+        Self::validate_flag(flag)?;
+
+        let mask = Self::permissions(env.clone(), user.clone()) | flag;
+        Self::set_permissions(&env, &user, mask);
+
+        env.events()
+            .publish((CONTRACT_NS, ACTION_GRANT, user), (flag, mask));
+        Ok(mask)
+    }
+
+    /// Clear the bits in `flag` for `user`, leaving the rest (admin-only).
+    pub fn revoke_permission(
+        env: Env,
+        admin: Address,
+        user: Address,
+        flag: u32,
+    ) -> Result<u32, PermissionError> {
+        Self::require_admin(&env, &admin)?;
+        Self::validate_flag(flag)?;
+
+        let mask = Self::permissions(env.clone(), user.clone()) & !flag;
+        Self::set_permissions(&env, &user, mask);
+
+        env.events()
+            .publish((CONTRACT_NS, ACTION_REVOKE, user), (flag, mask));
+        Ok(mask)
+    }
+
+    /// Whether `user` holds every bit in `flag`.
+    pub fn has_permission(env: Env, user: Address, flag: u32) -> bool {
+        flag != 0 && Self::permissions(env, user) & flag == flag
+    }
+
+    /// The raw bitmask for `user` (0 if nothing granted).
+    pub fn permissions(env: Env, user: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Permissions(user))
+            .unwrap_or(0)
+    }
+
+    // ── helpers ─────────────────────────────────────────────────────────────
+
+    fn set_permissions(env: &Env, user: &Address, mask: u32) {
+        let key = DataKey::Permissions(user.clone());
+        if mask == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &mask);
+        }
+    }
+
+    fn validate_flag(flag: u32) -> Result<(), PermissionError> {
+        if flag == 0 || flag & !ALL_FLAGS != 0 {
+            return Err(PermissionError::InvalidFlag);
+        }
+        Ok(())
+    }
+
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), PermissionError> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(PermissionError::NotInitialized)?;
+        if caller != &admin {
+            return Err(PermissionError::NotAuthorized);
+        }
+        caller.require_auth();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test;
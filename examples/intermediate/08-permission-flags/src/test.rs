@@ -0,0 +1,102 @@
+use super::*;
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+fn setup() -> (Env, Address, PermissionContractClient<'static>) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = PermissionContractClient::new(&env, &env.register(PermissionContract, ()));
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    (env, admin, client)
+}
+
+// ── granting ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_no_permissions_by_default() {
+    let (env, _admin, client) = setup();
+    let user = Address::generate(&env);
+    assert_eq!(client.permissions(&user), 0);
+    assert!(!client.has_permission(&user, &READ));
+}
+
+#[test]
+fn test_grant_combination() {
+    let (env, admin, client) = setup();
+    let user = Address::generate(&env);
+
+    assert_eq!(client.grant_permission(&admin, &user, &(READ | WRITE)), 3);
+    assert!(client.has_permission(&user, &READ));
+    assert!(client.has_permission(&user, &WRITE));
+    assert!(client.has_permission(&user, &(READ | WRITE)));
+    assert!(!client.has_permission(&user, &DELETE));
+    // Requires every requested bit
+    assert!(!client.has_permission(&user, &(READ | DELETE)));
+}
+
+#[test]
+fn test_grants_accumulate() {
+    let (env, admin, client) = setup();
+    let user = Address::generate(&env);
+
+    client.grant_permission(&admin, &user, &READ);
+    client.grant_permission(&admin, &user, &DELETE);
+    assert_eq!(client.permissions(&user), READ | DELETE);
+}
+
+#[test]
+fn test_grant_rejects_undefined_bits() {
+    let (env, admin, client) = setup();
+    let user = Address::generate(&env);
+
+    let result = client.try_grant_permission(&admin, &user, &8);
+    assert_eq!(result, Err(Ok(PermissionError::InvalidFlag)));
+    let result = client.try_grant_permission(&admin, &user, &0);
+    assert_eq!(result, Err(Ok(PermissionError::InvalidFlag)));
+}
+
+#[test]
+fn test_grant_requires_admin() {
+    let (env, _admin, client) = setup();
+    let user = Address::generate(&env);
+
+    let result = client.try_grant_permission(&user, &user, &READ);
+    assert_eq!(result, Err(Ok(PermissionError::NotAuthorized)));
+}
+
+// ── revoking ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_revoke_one_flag_preserves_others() {
+    let (env, admin, client) = setup();
+    let user = Address::generate(&env);
+
+    client.grant_permission(&admin, &user, &ALL_FLAGS);
+    assert_eq!(
+        client.revoke_permission(&admin, &user, &WRITE),
+        READ | DELETE
+    );
+
+    assert!(client.has_permission(&user, &READ));
+    assert!(!client.has_permission(&user, &WRITE));
+    assert!(client.has_permission(&user, &DELETE));
+}
+
+#[test]
+fn test_revoke_all_clears_mask() {
+    let (env, admin, client) = setup();
+    let user = Address::generate(&env);
+
+    client.grant_permission(&admin, &user, &(READ | WRITE));
+    client.revoke_permission(&admin, &user, &(READ | WRITE));
+    assert_eq!(client.permissions(&user), 0);
+}
+
+#[test]
+fn test_revoke_unheld_flag_is_noop() {
+    let (env, admin, client) = setup();
+    let user = Address::generate(&env);
+
+    client.grant_permission(&admin, &user, &READ);
+    assert_eq!(client.revoke_permission(&admin, &user, &DELETE), READ);
+}
@@ -0,0 +1,218 @@
+//! # Circuit Breaker
+//!
+//! Builds on `03-pause-unpause`: instead of waiting for an admin to notice a
+//! problem and pause by hand, the contract pauses **itself** when withdrawals
+//! inside a time window add up to more than a configured threshold.
+//!
+//! ## Operational Guidance
+//!
+//! - Withdrawals are summed over a fixed window of `window` seconds. The
+//!   total restarts from zero once the window has passed.
+//! - The withdrawal that pushes the total over the threshold is still
+//!   recorded, but it trips the breaker and every later withdrawal is
+//!   rejected with `BreakerError::BreakerTripped`.
+//! - Tripping is persisted by returning `Ok(true)`; returning an error would
+//!   roll the trip back along with everything else in the invocation.
+//! - Only the `monitor` address set at initialization may record
+//!   withdrawals, and it must sign each call. In practice this is the vault
+//!   whose outflows are being watched; without this check anyone could
+//!   report fake withdrawals and trip the breaker.
+//! - Only the admin can `reset` the breaker, which also clears the window.
+//!   There is no automatic recovery by design.
+
+#![no_std]
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Env, Symbol,
+};
+
+// ---------------------------------------------------------------------------
+// Errors
+// ---------------------------------------------------------------------------
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum BreakerError {
+    /// Contract has already been initialized.
+    AlreadyInitialized = 1,
+    /// Contract has not been initialized yet.
+    NotInitialized = 2,
+    /// Caller is not the admin (or, for `record_withdrawal`, the monitor).
+    NotAuthorized = 3,
+    /// Amount, threshold or window must be positive.
+    InvalidAmount = 4,
+    /// The breaker has tripped; withdrawals are blocked until reset.
+    BreakerTripped = 5,
+}
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,
+    /// Address allowed to record withdrawals.
+    Monitor,
+    /// Maximum total withdrawn per window before tripping.
+    Threshold,
+    /// Window length in seconds.
+    Window,
+    /// Timestamp the current window started.
+    WindowStart,
+    /// Total withdrawn in the current window.
+    WindowTotal,
+    Tripped,
+}
+
+// ---------------------------------------------------------------------------
+// Events
+// ---------------------------------------------------------------------------
+
+const CONTRACT_NS: Symbol = symbol_short!("breaker");
+const ACTION_TRIP: Symbol = symbol_short!("trip");
+const ACTION_RESET: Symbol = symbol_short!("reset");
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+#[contract]
+pub struct CircuitBreakerContract;
+
+#[contractimpl]
+impl CircuitBreakerContract {
+    /// Set the admin, the `monitor` allowed to record withdrawals, the
+    /// per-window threshold and the window length (seconds).
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        monitor: Address,
+        threshold: i128,
+        window: u64,
+    ) -> Result<(), BreakerError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(BreakerError::AlreadyInitialized);
+        }
+        if threshold <= 0 || window == 0 {
+            return Err(BreakerError::InvalidAmount);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Monitor, &monitor);
+        env.storage()
+            .instance()
+            .set(&DataKey::Threshold, &threshold);
+        env.storage().instance().set(&DataKey::Window, &window);
+        Self::start_window(&env);
+        env.storage().instance().set(&DataKey::Tripped, &false);
+        Ok(())
+    }
+
+    /// Record a withdrawal of `amount` (monitor-only).
+    ///
+    /// Returns `Ok(true)` if this withdrawal tripped the breaker.
+    pub fn record_withdrawal(
+        env: Env,
+        monitor: Address,
+        amount: i128,
+    ) -> Result<bool, BreakerError> {
+        let stored: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Monitor)
+            .ok_or(BreakerError::NotInitialized)?;
+        if monitor != stored {
+            return Err(BreakerError::NotAuthorized);
+        }
+        monitor.require_auth();
+
+        if Self::is_tripped(env.clone()) {
+            return Err(BreakerError::BreakerTripped);
+        }
+        if amount <= 0 {
+            return Err(BreakerError::InvalidAmount);
+        }
+        let threshold: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::Threshold)
+            .ok_or(BreakerError::NotInitialized)?;
+        let window: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::Window)
+            .ok_or(BreakerError::NotInitialized)?;
+
+        let window_start: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::WindowStart)
+            .unwrap_or(0);
+        if env.ledger().timestamp() >= window_start.saturating_add(window) {
+            Self::start_window(&env);
+        }
+
+        let total = Self::window_total(env.clone()).saturating_add(amount);
+        env.storage().instance().set(&DataKey::WindowTotal, &total);
+
+        if total <= threshold {
+            return Ok(false);
+        }
+
+        env.storage().instance().set(&DataKey::Tripped, &true);
+        env.events()
+            .publish((CONTRACT_NS, ACTION_TRIP), (total, threshold));
+        Ok(true)
+    }
+
+    /// Re-arm a tripped breaker and start a fresh window (admin-only).
+    pub fn reset(env: Env, admin: Address) -> Result<(), BreakerError> {
+        let stored: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(BreakerError::NotInitialized)?;
+        if admin != stored {
+            return Err(BreakerError::NotAuthorized);
+        }
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Tripped, &false);
+        Self::start_window(&env);
+
+        env.events()
+            .publish((CONTRACT_NS, ACTION_RESET, admin), env.ledger().timestamp());
+        Ok(())
+    }
+
+    // ── queries ─────────────────────────────────────────────────────────────
+
+    pub fn is_tripped(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::Tripped)
+            .unwrap_or(false)
+    }
+
+    /// Total withdrawn in the current window.
+    pub fn window_total(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::WindowTotal)
+            .unwrap_or(0)
+    }
+
+    // ── helpers ─────────────────────────────────────────────────────────────
+
+    fn start_window(env: &Env) {
+        env.storage()
+            .instance()
+            .set(&DataKey::WindowStart, &env.ledger().timestamp());
+        env.storage().instance().set(&DataKey::WindowTotal, &0i128);
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,123 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+const THRESHOLD: i128 = 1_000;
+const WINDOW: u64 = 3_600;
+
+fn setup() -> (Env, Address, Address, CircuitBreakerContractClient<'static>) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let client = CircuitBreakerContractClient::new(&env, &env.register(CircuitBreakerContract, ()));
+    let admin = Address::generate(&env);
+    let monitor = Address::generate(&env);
+    client.initialize(&admin, &monitor, &THRESHOLD, &WINDOW);
+    (env, admin, monitor, client)
+}
+
+// ── under the threshold ─────────────────────────────────────────────────────
+
+#[test]
+fn test_withdrawals_under_threshold() {
+    let (_env, _admin, monitor, client) = setup();
+
+    assert!(!client.record_withdrawal(&monitor, &400));
+    assert!(!client.record_withdrawal(&monitor, &600));
+    assert_eq!(client.window_total(), THRESHOLD);
+    assert!(!client.is_tripped());
+}
+
+#[test]
+fn test_window_rolls_over() {
+    let (env, _admin, monitor, client) = setup();
+
+    client.record_withdrawal(&monitor, &900);
+    env.ledger().set_timestamp(1_000 + WINDOW);
+    assert!(!client.record_withdrawal(&monitor, &900));
+    assert_eq!(client.window_total(), 900);
+}
+
+// ── tripping ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_crossing_threshold_trips_breaker() {
+    let (_env, _admin, monitor, client) = setup();
+
+    client.record_withdrawal(&monitor, &900);
+    assert!(client.record_withdrawal(&monitor, &101));
+    assert!(client.is_tripped());
+}
+
+#[test]
+fn test_withdrawals_blocked_after_trip() {
+    let (_env, _admin, monitor, client) = setup();
+
+    client.record_withdrawal(&monitor, &1_001);
+    let result = client.try_record_withdrawal(&monitor, &1);
+    assert_eq!(result, Err(Ok(BreakerError::BreakerTripped)));
+}
+
+#[test]
+fn test_trip_survives_window_rollover() {
+    let (env, _admin, monitor, client) = setup();
+
+    client.record_withdrawal(&monitor, &1_001);
+    env.ledger().set_timestamp(1_000 + 10 * WINDOW);
+    let result = client.try_record_withdrawal(&monitor, &1);
+    assert_eq!(result, Err(Ok(BreakerError::BreakerTripped)));
+}
+
+// ── reset ───────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_admin_reset_rearms_breaker() {
+    let (_env, admin, monitor, client) = setup();
+
+    client.record_withdrawal(&monitor, &1_001);
+    client.reset(&admin);
+
+    assert!(!client.is_tripped());
+    assert_eq!(client.window_total(), 0);
+    assert!(!client.record_withdrawal(&monitor, &500));
+}
+
+#[test]
+fn test_reset_requires_admin() {
+    let (env, _admin, monitor, client) = setup();
+    let other = Address::generate(&env);
+
+    client.record_withdrawal(&monitor, &1_001);
+    let result = client.try_reset(&other);
+    assert_eq!(result, Err(Ok(BreakerError::NotAuthorized)));
+    assert!(client.is_tripped());
+}
+
+// ── reporting ───────────────────────────────────────────────────────────────
+
+#[test]
+fn test_record_withdrawal_requires_monitor() {
+    let (env, admin, _monitor, client) = setup();
+
+    // Neither an outsider nor the admin can report withdrawals
+    for caller in [Address::generate(&env), admin] {
+        let result = client.try_record_withdrawal(&caller, &THRESHOLD.saturating_add(1));
+        assert_eq!(result, Err(Ok(BreakerError::NotAuthorized)));
+    }
+    assert!(!client.is_tripped());
+    assert_eq!(client.window_total(), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError")]
+fn test_record_withdrawal_requires_monitor_auth() {
+    let env = Env::default();
+    let client = CircuitBreakerContractClient::new(&env, &env.register(CircuitBreakerContract, ()));
+    let monitor = Address::generate(&env);
+    client.initialize(&Address::generate(&env), &monitor, &THRESHOLD, &WINDOW);
+
+    // No mocked auths: the monitor has not signed
+    client.record_withdrawal(&monitor, &1);
+}
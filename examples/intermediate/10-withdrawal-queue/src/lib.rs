@@ -0,0 +1,232 @@
+//! # Withdrawal Queue
+//!
+//! Combines the delay idea of a timelock with the throughput cap of a rate
+//! limiter: withdrawals are queued, and at most `epoch_cap` is paid out per
+//! epoch of `epoch_length` ledgers.
+//!
+//! ## Operational Guidance
+//!
+//! - `request_withdrawal` only enqueues; nothing is paid until `process`.
+//! - `process` can be called by anyone (e.g. a keeper bot). It pays requests
+//!   strictly in FIFO order until the epoch's cap is used up.
+//! - If the request at the head of the queue is larger than what is left of
+//!   the cap, it is paid partially and the remainder stays at the head, to
+//!   be paid first in the next epoch.
+//! - Epochs are derived from the ledger sequence
+//!   (`sequence / epoch_length`), so they advance without any transaction.
+//! - Payouts are tracked inside the contract to keep the example
+//!   self-contained; a real contract would transfer a token instead.
+
+#![no_std]
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Env, Symbol, Vec,
+};
+
+// ---------------------------------------------------------------------------
+// Errors
+// ---------------------------------------------------------------------------
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum QueueError {
+    /// Contract has already been initialized.
+    AlreadyInitialized = 1,
+    /// Contract has not been initialized yet.
+    NotInitialized = 2,
+    /// Amount, cap or epoch length must be positive.
+    InvalidAmount = 3,
+}
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// A queued withdrawal. `amount` is what is still owed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalRequest {
+    pub id: u64,
+    pub user: Address,
+    pub amount: i128,
+}
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    /// Maximum amount released per epoch.
+    EpochCap,
+    /// Epoch length in ledgers.
+    EpochLength,
+    /// Epoch in which `Released` was accumulated.
+    Epoch,
+    /// Amount released so far in `Epoch`.
+    Released,
+    /// Pending requests, head first.
+    Queue,
+    /// Id assigned to the next request.
+    NextId,
+    /// Total paid out to an address.
+    Paid(Address),
+}
+
+// ---------------------------------------------------------------------------
+// Events
+// ---------------------------------------------------------------------------
+
+const CONTRACT_NS: Symbol = symbol_short!("wqueue");
+const ACTION_REQUEST: Symbol = symbol_short!("request");
+const ACTION_RELEASE: Symbol = symbol_short!("release");
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+#[contract]
+pub struct WithdrawalQueueContract;
+
+#[contractimpl]
+impl WithdrawalQueueContract {
+    /// Set the per-epoch cap and the epoch length (in ledgers).
+    pub fn initialize(env: Env, epoch_cap: i128, epoch_length: u32) -> Result<(), QueueError> {
+        if env.storage().instance().has(&DataKey::EpochCap) {
+            return Err(QueueError::AlreadyInitialized);
+        }
+        if epoch_cap <= 0 || epoch_length == 0 {
+            return Err(QueueError::InvalidAmount);
+        }
+        env.storage().instance().set(&DataKey::EpochCap, &epoch_cap);
+        env.storage()
+            .instance()
+            .set(&DataKey::EpochLength, &epoch_length);
+        Ok(())
+    }
+
+    /// Queue a withdrawal of `amount` for `user`. Returns the request id.
+    pub fn request_withdrawal(env: Env, user: Address, amount: i128) -> Result<u64, QueueError> {
+        user.require_auth();
+        if !env.storage().instance().has(&DataKey::EpochCap) {
+            return Err(QueueError::NotInitialized);
+        }
+        if amount <= 0 {
+            return Err(QueueError::InvalidAmount);
+        }
+
+        let id: u64 = env.storage().instance().get(&DataKey::NextId).unwrap_or(0);
+        env.storage().instance().set(&DataKey::NextId, &(id + 1));
+
+        let mut queue = Self::queue(env.clone());
+        queue.push_back(WithdrawalRequest {
+            id,
+            user: user.clone(),
+            amount,
+        });
+        env.storage().persistent().set(&DataKey::Queue, &queue);
+
+        env.events()
+            .publish((CONTRACT_NS, ACTION_REQUEST, user), (id, amount));
+        Ok(id)
+    }
+
+    /// Pay queued requests in FIFO order, up to what is left of this epoch's
+    /// cap. Returns the amount released by this call.
+    pub fn process(env: Env) -> Result<i128, QueueError> {
+        let cap: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::EpochCap)
+            .ok_or(QueueError::NotInitialized)?;
+
+        let mut remaining = cap - Self::released_this_epoch(env.clone());
+        let mut queue = Self::queue(env.clone());
+        let mut released = 0i128;
+
+        while remaining > 0 {
+            let Some(mut head) = queue.first() else {
+                break;
+            };
+            let pay = head.amount.min(remaining);
+
+            let paid = Self::paid(env.clone(), head.user.clone());
+            env.storage()
+                .persistent()
+                .set(&DataKey::Paid(head.user.clone()), &(paid + pay));
+            env.events().publish(
+                (CONTRACT_NS, ACTION_RELEASE, head.user.clone()),
+                (head.id, pay),
+            );
+
+            head.amount -= pay;
+            if head.amount == 0 {
+                queue.pop_front();
+            } else {
+                // Partially paid: the rest stays at the head for the next epoch
+                queue.set(0, head);
+            }
+            remaining -= pay;
+            released += pay;
+        }
+
+        env.storage().persistent().set(&DataKey::Queue, &queue);
+        env.storage()
+            .instance()
+            .set(&DataKey::Epoch, &Self::current_epoch(env.clone()));
+        env.storage()
+            .instance()
+            .set(&DataKey::Released, &(cap - remaining));
+        Ok(released)
+    }
+
+    // ── queries ─────────────────────────────────────────────────────────────
+
+    /// Pending requests, head first.
+    pub fn queue(env: Env) -> Vec<WithdrawalRequest> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Queue)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Sum of all amounts still waiting in the queue.
+    pub fn pending(env: Env) -> i128 {
+        Self::queue(env).iter().map(|r| r.amount).sum()
+    }
+
+    /// Total paid out to `user`.
+    pub fn paid(env: Env, user: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Paid(user))
+            .unwrap_or(0)
+    }
+
+    /// Index of the current epoch (`ledger sequence / epoch_length`).
+    pub fn current_epoch(env: Env) -> u32 {
+        let length: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::EpochLength)
+            .unwrap_or(1);
+        env.ledger().sequence() / length
+    }
+
+    /// Amount already released in the current epoch.
+    pub fn released_this_epoch(env: Env) -> i128 {
+        let epoch: Option<u32> = env.storage().instance().get(&DataKey::Epoch);
+        if epoch != Some(Self::current_epoch(env.clone())) {
+            return 0;
+        }
+        env.storage()
+            .instance()
+            .get(&DataKey::Released)
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,124 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+const CAP: i128 = 1_000;
+const EPOCH: u32 = 100;
+
+fn setup() -> (Env, WithdrawalQueueContractClient<'static>) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_sequence_number(EPOCH * 10);
+    let client =
+        WithdrawalQueueContractClient::new(&env, &env.register(WithdrawalQueueContract, ()));
+    client.initialize(&CAP, &EPOCH);
+    (env, client)
+}
+
+fn next_epoch(env: &Env) {
+    env.ledger().with_mut(|li| li.sequence_number += EPOCH);
+}
+
+// ── requests ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_request_enqueues_without_paying() {
+    let (env, client) = setup();
+    let user = Address::generate(&env);
+
+    assert_eq!(client.request_withdrawal(&user, &300), 0);
+    assert_eq!(client.request_withdrawal(&user, &200), 1);
+    assert_eq!(client.queue().len(), 2);
+    assert_eq!(client.pending(), 500);
+    assert_eq!(client.paid(&user), 0);
+}
+
+#[test]
+fn test_request_rejects_non_positive_amount() {
+    let (env, client) = setup();
+    let user = Address::generate(&env);
+    let result = client.try_request_withdrawal(&user, &0);
+    assert_eq!(result, Err(Ok(QueueError::InvalidAmount)));
+}
+
+// ── processing ──────────────────────────────────────────────────────────────
+
+#[test]
+fn test_process_under_cap_releases_everything() {
+    let (env, client) = setup();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    client.request_withdrawal(&alice, &400);
+    client.request_withdrawal(&bob, &500);
+
+    assert_eq!(client.process(), 900);
+    assert_eq!(client.paid(&alice), 400);
+    assert_eq!(client.paid(&bob), 500);
+    assert_eq!(client.queue().len(), 0);
+    assert_eq!(client.released_this_epoch(), 900);
+}
+
+#[test]
+fn test_process_stops_at_cap_and_carries_remainder() {
+    let (env, client) = setup();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+
+    client.request_withdrawal(&alice, &600);
+    client.request_withdrawal(&bob, &700);
+    client.request_withdrawal(&carol, &100);
+
+    // Alice in full, Bob partially; Carol waits behind Bob
+    assert_eq!(client.process(), CAP);
+    assert_eq!(client.paid(&alice), 600);
+    assert_eq!(client.paid(&bob), 400);
+    assert_eq!(client.paid(&carol), 0);
+    assert_eq!(client.pending(), 400);
+
+    // Same epoch: nothing more can be released
+    assert_eq!(client.process(), 0);
+
+    next_epoch(&env);
+    assert_eq!(client.process(), 400);
+    assert_eq!(client.paid(&bob), 700);
+    assert_eq!(client.paid(&carol), 100);
+    assert_eq!(client.queue().len(), 0);
+}
+
+#[test]
+fn test_requests_after_processing_share_epoch_cap() {
+    let (env, client) = setup();
+    let user = Address::generate(&env);
+
+    client.request_withdrawal(&user, &800);
+    client.process();
+
+    client.request_withdrawal(&user, &500);
+    assert_eq!(client.process(), 200);
+    assert_eq!(client.pending(), 300);
+
+    next_epoch(&env);
+    assert_eq!(client.process(), 300);
+    assert_eq!(client.paid(&user), 1_300);
+}
+
+#[test]
+fn test_process_keeps_fifo_order() {
+    let (env, client) = setup();
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+
+    client.request_withdrawal(&first, &1_500);
+    client.request_withdrawal(&second, &10);
+
+    client.process();
+    assert_eq!(client.paid(&first), 1_000);
+    assert_eq!(client.paid(&second), 0);
+    let head = client.queue().get(0).unwrap();
+    assert_eq!(head.id, 0);
+    assert_eq!(head.amount, 500);
+}
@@ -0,0 +1,11 @@
+#!/usr/bin/env bash
+# Build the Wasm fixtures that example tests load with `contractimport!`.
+# Run from anywhere before `cargo test` on the workspace or on those examples.
+set -euo pipefail
+
+ROOT="$(cd "$(dirname "${BASH_SOURCE[0]}")/.." && pwd)"
+
+cd "$ROOT"
+cargo build --target wasm32v1-none --release \
+  -p factory-child \
+  -p upgradeable-counter-v2
@@ -0,0 +1,219 @@
+//! # Access Control
+//!
+//! Reusable role storage and checks, generic over a contract-defined role
+//! enum. The contract implements [`RoleType`] for its enum to describe the
+//! hierarchy; this module handles storage, lookups and the guards.
+//!
+//! Roles live in persistent storage under [`AccessKey::Role`]. The number of
+//! addresses holding an admin role is tracked in instance storage under
+//! [`AccessKey::AdminCount`] so [`require_not_last_admin`] can stop a
+//! contract from locking itself out.
+
+use crate::ValidationError;
+use soroban_sdk::{contracttype, Address, Env, IntoVal, TryFromVal, Val};
+
+/// A contract's role enum.
+pub trait RoleType: Copy + IntoVal<Env, Val> + TryFromVal<Env, Val> {
+    /// Whether holding `self` satisfies a check for `required`.
+    /// Hierarchies are expressed here, e.g. an admin satisfies a moderator check.
+    fn grants(&self, required: &Self) -> bool;
+
+    /// Whether this role counts towards the admin total.
+    fn is_admin(&self) -> bool;
+}
+
+/// Storage keys owned by this module.
+#[contracttype]
+#[derive(Clone)]
+pub enum AccessKey {
+    Role(Address),
+    AdminCount,
+}
+
+/// Returns the role assigned to `addr`, if any.
+pub fn get_role<R: RoleType>(env: &Env, addr: &Address) -> Option<R> {
+    env.storage()
+        .persistent()
+        .get(&AccessKey::Role(addr.clone()))
+}
+
+/// Assign `role` to `addr`, replacing any previous role.
+pub fn set_role<R: RoleType>(env: &Env, addr: &Address, role: R) {
+    let was_admin = get_role::<R>(env, addr).is_some_and(|r| r.is_admin());
+    env.storage()
+        .persistent()
+        .set(&AccessKey::Role(addr.clone()), &role);
+    adjust_admin_count(env, was_admin, role.is_admin());
+}
+
+/// Remove any role assigned to `addr`.
+pub fn remove_role<R: RoleType>(env: &Env, addr: &Address) {
+    let was_admin = get_role::<R>(env, addr).is_some_and(|r| r.is_admin());
+    env.storage()
+        .persistent()
+        .remove(&AccessKey::Role(addr.clone()));
+    adjust_admin_count(env, was_admin, false);
+}
+
+/// Number of addresses currently holding an admin role.
+pub fn admin_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&AccessKey::AdminCount)
+        .unwrap_or(0)
+}
+
+/// Pattern for role-gated functions
+///
+/// # Errors
+/// * `ValidationError::InsufficientRole` - If `caller` has no role, or its
+///   role grants none of `allowed`
+pub fn require_role<R: RoleType>(
+    env: &Env,
+    caller: &Address,
+    allowed: &[R],
+) -> Result<(), ValidationError> {
+    match get_role::<R>(env, caller) {
+        Some(role) if allowed.iter().any(|required| role.grants(required)) => Ok(()),
+        _ => Err(ValidationError::InsufficientRole),
+    }
+}
+
+/// Guard to call before demoting or removing `addr`.
+///
+/// # Errors
+/// * `ValidationError::InvariantViolation` - If `addr` is the only admin left
+pub fn require_not_last_admin<R: RoleType>(
+    env: &Env,
+    addr: &Address,
+) -> Result<(), ValidationError> {
+    let is_admin = get_role::<R>(env, addr).is_some_and(|r| r.is_admin());
+    if is_admin && admin_count(env) <= 1 {
+        return Err(ValidationError::InvariantViolation);
+    }
+    Ok(())
+}
+
+fn adjust_admin_count(env: &Env, was_admin: bool, is_admin: bool) {
+    let count = admin_count(env);
+    let count = match (was_admin, is_admin) {
+        (false, true) => count + 1,
+        (true, false) => count.saturating_sub(1),
+        _ => return,
+    };
+    env.storage().instance().set(&AccessKey::AdminCount, &count);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{contract, contracttype};
+
+    #[contracttype]
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    #[repr(u32)]
+    enum TestRole {
+        Admin = 0,
+        Editor = 1,
+        Viewer = 2,
+    }
+
+    impl RoleType for TestRole {
+        fn grants(&self, required: &Self) -> bool {
+            (*self as u32) <= (*required as u32)
+        }
+
+        fn is_admin(&self) -> bool {
+            *self == TestRole::Admin
+        }
+    }
+
+    #[contract]
+    struct AccessHost;
+
+    fn setup() -> (Env, Address) {
+        let env = Env::default();
+        let id = env.register(AccessHost, ());
+        (env, id)
+    }
+
+    #[test]
+    fn test_role_assignment() {
+        let (env, id) = setup();
+        let user = Address::generate(&env);
+        env.as_contract(&id, || {
+            assert_eq!(get_role::<TestRole>(&env, &user), None);
+
+            set_role(&env, &user, TestRole::Editor);
+            assert_eq!(get_role(&env, &user), Some(TestRole::Editor));
+
+            set_role(&env, &user, TestRole::Viewer);
+            assert_eq!(get_role(&env, &user), Some(TestRole::Viewer));
+
+            remove_role::<TestRole>(&env, &user);
+            assert_eq!(get_role::<TestRole>(&env, &user), None);
+        });
+    }
+
+    #[test]
+    fn test_require_role_follows_hierarchy() {
+        let (env, id) = setup();
+        let editor = Address::generate(&env);
+        let nobody = Address::generate(&env);
+        env.as_contract(&id, || {
+            set_role(&env, &editor, TestRole::Editor);
+
+            assert_eq!(require_role(&env, &editor, &[TestRole::Viewer]), Ok(()));
+            assert_eq!(require_role(&env, &editor, &[TestRole::Editor]), Ok(()));
+            assert_eq!(
+                require_role(&env, &editor, &[TestRole::Admin]),
+                Err(ValidationError::InsufficientRole)
+            );
+            assert_eq!(
+                require_role(&env, &nobody, &[TestRole::Viewer]),
+                Err(ValidationError::InsufficientRole)
+            );
+        });
+    }
+
+    #[test]
+    fn test_admin_count_tracks_changes() {
+        let (env, id) = setup();
+        let a = Address::generate(&env);
+        let b = Address::generate(&env);
+        env.as_contract(&id, || {
+            set_role(&env, &a, TestRole::Admin);
+            set_role(&env, &b, TestRole::Admin);
+            // Re-assigning the same role does not double count
+            set_role(&env, &b, TestRole::Admin);
+            assert_eq!(admin_count(&env), 2);
+
+            set_role(&env, &a, TestRole::Viewer);
+            assert_eq!(admin_count(&env), 1);
+            remove_role::<TestRole>(&env, &b);
+            assert_eq!(admin_count(&env), 0);
+        });
+    }
+
+    #[test]
+    fn test_last_admin_protection() {
+        let (env, id) = setup();
+        let a = Address::generate(&env);
+        let b = Address::generate(&env);
+        env.as_contract(&id, || {
+            set_role(&env, &a, TestRole::Admin);
+            assert_eq!(
+                require_not_last_admin::<TestRole>(&env, &a),
+                Err(ValidationError::InvariantViolation)
+            );
+
+            set_role(&env, &b, TestRole::Admin);
+            assert_eq!(require_not_last_admin::<TestRole>(&env, &a), Ok(()));
+
+            // Non-admins are never blocked
+            set_role(&env, &a, TestRole::Editor);
+            assert_eq!(require_not_last_admin::<TestRole>(&env, &a), Ok(()));
+        });
+    }
+}
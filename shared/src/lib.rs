@@ -17,6 +17,14 @@
 #![no_std]
 use soroban_sdk::{contracterror, Address, Env, String, Vec};
 
+pub mod access_control;
+pub mod pausable;
+pub mod timelock;
+
+#[cfg(feature = "testutils")]
+pub mod test_budget;
+#[cfg(feature = "testutils")]
+pub mod test_errors;
 #[cfg(feature = "testutils")]
 pub mod test_events;
 
@@ -0,0 +1,171 @@
+//! # Pausable
+//!
+//! A contract-wide circuit breaker with three states:
+//!
+//! * `Active`       -- normal operation.
+//! * `Paused`       -- blocked until explicitly unpaused.
+//! * `Frozen(until)` -- blocked until the ledger timestamp reaches `until`,
+//!   after which the contract is active again without another transaction.
+//!
+//! Expiry is resolved on read by [`state`], so every check goes through the
+//! same rule: a freeze is in effect while `now < until`.
+
+use crate::ValidationError;
+use soroban_sdk::{contracttype, panic_with_error, Env};
+
+/// Stored pause state.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PauseState {
+    Active,
+    Paused,
+    Frozen(u64),
+}
+
+/// Storage keys owned by this module.
+#[contracttype]
+#[derive(Clone)]
+pub enum PauseKey {
+    State,
+}
+
+/// Pause until [`unpause`] is called.
+pub fn pause(env: &Env) {
+    write(env, PauseState::Paused);
+}
+
+/// Freeze until the ledger timestamp `until`.
+pub fn freeze(env: &Env, until: u64) {
+    write(env, PauseState::Frozen(until));
+}
+
+/// Return to normal operation, clearing any pause or freeze.
+pub fn unpause(env: &Env) {
+    env.storage().instance().remove(&PauseKey::State);
+}
+
+/// Current state, with an expired freeze reported as `Active`.
+pub fn state(env: &Env) -> PauseState {
+    let stored = env
+        .storage()
+        .instance()
+        .get(&PauseKey::State)
+        .unwrap_or(PauseState::Active);
+    match stored {
+        PauseState::Frozen(until) if env.ledger().timestamp() >= until => PauseState::Active,
+        other => other,
+    }
+}
+
+/// Whether operations are currently blocked.
+pub fn is_paused(env: &Env) -> bool {
+    state(env) != PauseState::Active
+}
+
+/// Guard for entry points that must not run while paused or frozen.
+///
+/// # Panics
+/// * `ValidationError::ContractPaused` - If paused
+/// * `ValidationError::ContractFrozen` - If frozen and the freeze has not expired
+pub fn require_not_paused(env: &Env) {
+    match state(env) {
+        PauseState::Active => {}
+        PauseState::Paused => panic_with_error!(env, ValidationError::ContractPaused),
+        PauseState::Frozen(_) => panic_with_error!(env, ValidationError::ContractFrozen),
+    }
+}
+
+fn write(env: &Env, state: PauseState) {
+    env.storage().instance().set(&PauseKey::State, &state);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::contract;
+    use soroban_sdk::testutils::Ledger;
+
+    #[contract]
+    struct PausableHost;
+
+    fn setup() -> (Env, soroban_sdk::Address) {
+        let env = Env::default();
+        env.ledger().with_mut(|li| li.timestamp = 1000);
+        let id = env.register(PausableHost, ());
+        (env, id)
+    }
+
+    #[test]
+    fn test_state_transitions() {
+        let (env, id) = setup();
+        env.as_contract(&id, || {
+            assert_eq!(state(&env), PauseState::Active);
+
+            pause(&env);
+            assert_eq!(state(&env), PauseState::Paused);
+
+            freeze(&env, 2000);
+            assert_eq!(state(&env), PauseState::Frozen(2000));
+
+            unpause(&env);
+            assert_eq!(state(&env), PauseState::Active);
+            assert!(!is_paused(&env));
+        });
+    }
+
+    #[test]
+    fn test_freeze_expires_automatically() {
+        let (env, id) = setup();
+        env.as_contract(&id, || freeze(&env, 1500));
+
+        env.ledger().with_mut(|li| li.timestamp = 1499);
+        env.as_contract(&id, || assert!(is_paused(&env)));
+
+        // Active again from the expiry timestamp itself, with no unpause call
+        env.ledger().with_mut(|li| li.timestamp = 1500);
+        env.as_contract(&id, || {
+            assert_eq!(state(&env), PauseState::Active);
+            require_not_paused(&env);
+        });
+    }
+
+    #[test]
+    fn test_pause_does_not_expire() {
+        let (env, id) = setup();
+        env.as_contract(&id, || pause(&env));
+
+        env.ledger().with_mut(|li| li.timestamp = u64::MAX);
+        env.as_contract(&id, || assert_eq!(state(&env), PauseState::Paused));
+    }
+
+    #[test]
+    fn test_require_not_paused_allows_active() {
+        let (env, id) = setup();
+        env.as_contract(&id, || {
+            require_not_paused(&env);
+            pause(&env);
+            unpause(&env);
+            require_not_paused(&env);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #201)")]
+    fn test_require_not_paused_panics_when_paused() {
+        let (env, id) = setup();
+        env.as_contract(&id, || {
+            pause(&env);
+            require_not_paused(&env);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #202)")]
+    fn test_require_not_paused_panics_when_frozen() {
+        let (env, id) = setup();
+        env.as_contract(&id, || {
+            freeze(&env, 1001);
+            require_not_paused(&env);
+        });
+    }
+}
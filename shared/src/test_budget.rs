@@ -0,0 +1,31 @@
+//! Test helpers for measuring the host budget consumed by contract calls.
+//!
+//! Use [`measure`] to build resource regression tests: run the call, then
+//! assert the returned [`Cost`] stays under a documented ceiling.
+
+use soroban_sdk::Env;
+
+/// CPU instructions and memory bytes charged to the host budget.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Cost {
+    pub cpu_instructions: u64,
+    pub memory_bytes: u64,
+}
+
+/// Runs `f` against a freshly reset budget and returns its result together
+/// with the [`Cost`] it consumed.
+///
+/// Contracts registered natively (not from Wasm) skip VM instantiation and
+/// execution, so the numbers are only meaningful relative to earlier runs of
+/// the same test, not as absolute on-chain costs.
+pub fn measure<T>(env: &Env, f: impl FnOnce() -> T) -> (T, Cost) {
+    let mut budget = env.cost_estimate().budget();
+    budget.reset_default();
+    let result = f();
+    let budget = env.cost_estimate().budget();
+    let cost = Cost {
+        cpu_instructions: budget.cpu_instruction_cost(),
+        memory_bytes: budget.memory_bytes_cost(),
+    };
+    (result, cost)
+}
@@ -0,0 +1,89 @@
+//! Test helpers for asserting contract errors by code.
+//!
+//! `#[should_panic(expected = "Error(Contract, #3)")]` ties a test to the
+//! host's panic message. [`assert_contract_err`] decodes the result of a
+//! generated `try_*` client call instead and compares the numeric code.
+
+use core::fmt::Debug;
+use soroban_sdk::xdr::ScErrorType;
+use soroban_sdk::{Error, InvokeError};
+
+/// Asserts that a `try_*` client call failed with contract error `expected`.
+///
+/// Accepts both shapes a contract error can take: a value of the contract's
+/// own error enum (`Err(Ok(e))`), and a raw code that enum does not cover
+/// (`Err(Err(InvokeError::Contract(code)))`), e.g. from `panic_with_error!`
+/// with a shared error type. Panics on success, aborts and host errors.
+pub fn assert_contract_err<T, C, E>(
+    result: Result<Result<T, C>, Result<E, InvokeError>>,
+    expected: impl Into<u32>,
+) where
+    T: Debug,
+    C: Debug,
+    E: Into<Error>,
+{
+    let expected = expected.into();
+    let code = match result {
+        Ok(value) => panic!("expected contract error #{expected}, call returned {value:?}"),
+        Err(Ok(err)) => {
+            let err: Error = err.into();
+            if !err.is_type(ScErrorType::Contract) {
+                panic!("expected contract error #{expected}, got {err:?}");
+            }
+            err.get_code()
+        }
+        Err(Err(InvokeError::Contract(code))) => code,
+        Err(Err(InvokeError::Abort)) => {
+            panic!("expected contract error #{expected}, call aborted")
+        }
+    };
+    assert_eq!(code, expected, "unexpected contract error code");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ValidationError;
+    use soroban_sdk::{contract, contractimpl, Env};
+
+    #[contract]
+    struct ErrorHost;
+
+    #[contractimpl]
+    impl ErrorHost {
+        pub fn check(_env: Env, ok: bool) -> Result<u32, ValidationError> {
+            if ok {
+                Ok(1)
+            } else {
+                Err(ValidationError::NotAdmin)
+            }
+        }
+    }
+
+    #[test]
+    fn test_matches_known_code() {
+        let env = Env::default();
+        let client = ErrorHostClient::new(&env, &env.register(ErrorHost, ()));
+        assert_contract_err(client.try_check(&false), ValidationError::NotAdmin as u32);
+        assert_contract_err(client.try_check(&false), 301u32);
+    }
+
+    #[test]
+    #[should_panic(expected = "unexpected contract error code")]
+    fn test_wrong_code_panics() {
+        let env = Env::default();
+        let client = ErrorHostClient::new(&env, &env.register(ErrorHost, ()));
+        assert_contract_err(
+            client.try_check(&false),
+            ValidationError::Unauthorized as u32,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "call returned")]
+    fn test_success_panics() {
+        let env = Env::default();
+        let client = ErrorHostClient::new(&env, &env.register(ErrorHost, ()));
+        assert_contract_err(client.try_check(&true), ValidationError::NotAdmin as u32);
+    }
+}
@@ -2,10 +2,11 @@
 //!
 //! `ContractEvents` no longer implements collection methods directly; use
 //! [`EventList`] to preserve the pre-26 test API (`len`, `get`, `last`, etc.).
+//! [`assert_last_event`] covers the common "last event is `(topic, u32)`" check.
 
-use soroban_sdk::testutils::ContractEvents;
+use soroban_sdk::testutils::{ContractEvents, Events as _};
 use soroban_sdk::xdr::{ContractEventBody, ScAddress};
-use soroban_sdk::{Address, Env, TryFromVal, Val, Vec};
+use soroban_sdk::{Address, Env, Symbol, TryFromVal, Val, Vec};
 
 /// Wrapper around [`ContractEvents`] with legacy-friendly accessors for tests.
 #[derive(Clone)]
@@ -44,6 +45,34 @@ impl EventList {
     }
 }
 
+/// Asserts that the most recent event came from `contract_id`, has
+/// `expected_topic0` as its first topic and carries `expected_data` as a
+/// `u32` payload.
+///
+/// Only the last invocation's events are visible, so call this straight
+/// after the call under test, before any read-only client calls.
+pub fn assert_last_event(
+    env: &Env,
+    contract_id: &Address,
+    expected_topic0: Symbol,
+    expected_data: u32,
+) {
+    let (id, topics, data) = EventList::new(env, env.events().all())
+        .last()
+        .expect("assert_last_event: no events were emitted");
+    assert_eq!(&id, contract_id, "assert_last_event: wrong contract");
+
+    let topic0 = topics
+        .get(0)
+        .expect("assert_last_event: event has no topics");
+    let topic0 =
+        Symbol::try_from_val(env, &topic0).expect("assert_last_event: topic 0 is not a Symbol");
+    assert_eq!(topic0, expected_topic0, "assert_last_event: wrong topic 0");
+
+    let data = u32::try_from_val(env, &data).expect("assert_last_event: data is not a u32");
+    assert_eq!(data, expected_data, "assert_last_event: wrong data");
+}
+
 fn decode_event(
     env: &Env,
     event: &soroban_sdk::xdr::ContractEvent,
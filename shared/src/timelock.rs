@@ -0,0 +1,144 @@
+//! # Timelock
+//!
+//! Reusable helpers for "not before" restrictions. A timelock is stored as
+//! the absolute ledger timestamp at which it unlocks, under a key chosen by
+//! the calling contract, in instance storage.
+//!
+//! A key with nothing stored counts as unlocked, so contracts can check a
+//! timelock that was never set without special-casing it.
+
+use soroban_sdk::{Env, IntoVal, Val};
+
+/// Lock `key` for `duration` seconds from the current ledger timestamp.
+///
+/// Returns the unlock timestamp that was stored.
+pub fn set_timelock<K>(env: &Env, key: &K, duration: u64) -> u64
+where
+    K: IntoVal<Env, Val>,
+{
+    let unlock_at = env.ledger().timestamp().saturating_add(duration);
+    set_unlock_time(env, key, unlock_at);
+    unlock_at
+}
+
+/// Lock `key` until the absolute ledger timestamp `unlock_at`.
+pub fn set_unlock_time<K>(env: &Env, key: &K, unlock_at: u64)
+where
+    K: IntoVal<Env, Val>,
+{
+    env.storage().instance().set(key, &unlock_at);
+}
+
+/// Returns the stored unlock timestamp for `key`, or 0 if none is set.
+pub fn unlock_time<K>(env: &Env, key: &K) -> u64
+where
+    K: IntoVal<Env, Val>,
+{
+    env.storage().instance().get(key).unwrap_or(0)
+}
+
+/// Whether the timelock under `key` has elapsed.
+///
+/// The lock is considered elapsed from the unlock timestamp itself onward.
+pub fn is_elapsed<K>(env: &Env, key: &K) -> bool
+where
+    K: IntoVal<Env, Val>,
+{
+    env.ledger().timestamp() >= unlock_time(env, key)
+}
+
+/// Seconds left until the timelock under `key` elapses (0 once elapsed).
+pub fn remaining<K>(env: &Env, key: &K) -> u64
+where
+    K: IntoVal<Env, Val>,
+{
+    unlock_time(env, key).saturating_sub(env.ledger().timestamp())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Ledger as _;
+    use soroban_sdk::{contract, symbol_short, Address};
+
+    #[contract]
+    struct TimelockHost;
+
+    fn setup(start: u64) -> (Env, Address) {
+        let env = Env::default();
+        env.ledger().with_mut(|li| li.timestamp = start);
+        let id = env.register(TimelockHost, ());
+        (env, id)
+    }
+
+    #[test]
+    fn test_unset_timelock_is_elapsed() {
+        let (env, id) = setup(1_000);
+        env.as_contract(&id, || {
+            let key = symbol_short!("lock");
+            assert!(is_elapsed(&env, &key));
+            assert_eq!(remaining(&env, &key), 0);
+        });
+    }
+
+    #[test]
+    fn test_not_yet_elapsed() {
+        let (env, id) = setup(1_000);
+        let key = symbol_short!("lock");
+        env.as_contract(&id, || {
+            assert_eq!(set_timelock(&env, &key, 100), 1_100);
+        });
+
+        env.ledger().with_mut(|li| li.timestamp = 1_099);
+        env.as_contract(&id, || {
+            assert!(!is_elapsed(&env, &key));
+            assert_eq!(remaining(&env, &key), 1);
+        });
+    }
+
+    #[test]
+    fn test_exactly_elapsed() {
+        let (env, id) = setup(1_000);
+        let key = symbol_short!("lock");
+        env.as_contract(&id, || {
+            set_timelock(&env, &key, 100);
+        });
+
+        env.ledger().with_mut(|li| li.timestamp = 1_100);
+        env.as_contract(&id, || {
+            assert!(is_elapsed(&env, &key));
+            assert_eq!(remaining(&env, &key), 0);
+        });
+    }
+
+    #[test]
+    fn test_past_elapsed() {
+        let (env, id) = setup(1_000);
+        let key = symbol_short!("lock");
+        env.as_contract(&id, || {
+            set_timelock(&env, &key, 100);
+        });
+
+        env.ledger().with_mut(|li| li.timestamp = 5_000);
+        env.as_contract(&id, || {
+            assert!(is_elapsed(&env, &key));
+            assert_eq!(remaining(&env, &key), 0);
+        });
+    }
+
+    #[test]
+    fn test_keys_are_independent() {
+        let (env, id) = setup(1_000);
+        env.as_contract(&id, || {
+            set_timelock(&env, &symbol_short!("short"), 10);
+            set_timelock(&env, &symbol_short!("long"), 1_000);
+        });
+
+        env.ledger().with_mut(|li| li.timestamp = 1_010);
+        env.as_contract(&id, || {
+            assert!(is_elapsed(&env, &symbol_short!("short")));
+            assert!(!is_elapsed(&env, &symbol_short!("long")));
+            assert_eq!(remaining(&env, &symbol_short!("long")), 990);
+        });
+    }
+}
@@ -2589,7 +2589,7 @@ fn test_proxy_admin_pause() {
     client.initialize(&admin).unwrap();
     assert!(!client.is_paused());
 
-    client.pause().unwrap();
+    client.pause(&symbol_short!("incident")).unwrap();
     assert!(client.is_paused());
 
     client.unpause().unwrap();